@@ -0,0 +1,118 @@
+use crate::{
+	ConstraintViolation, Constrained,
+	constraint::{ RelativeLenConstraint, Relative },
+	same_len::{ SameLen, SliceRef },
+	type_math::{ Add, _0 }
+};
+use core::{
+	marker::PhantomData,
+	ops::{ Deref, DerefMut }
+};
+
+
+/// An invariant lifetime that identifies a single `scope` invocation
+type Id<'id> = PhantomData<fn(&'id ()) -> &'id ()>;
+
+
+/// Opens a branded scope for `anchor` and passes the branded anchor together with its length token
+/// to `f`
+///
+/// Every invocation produces a fresh and unnameable brand `'id`, so a `Len<'id>` cannot be mixed up
+/// with the token of another scope:
+/// ```compile_fail
+/// # use len_constraints::branded::{ self, Len };
+/// let (a, b): (&[u8], &[u8]) = (&[0; 4], &[0; 8]);
+/// branded::scope(a, |_, len_a| branded::scope(b, |_, len_b| {
+/// 	let _same: [Len; 2] = [len_a, len_b];
+/// }));
+/// ```
+pub fn scope<T, P: Deref<Target = [T]>, R>(anchor: P,
+	f: impl for<'id> FnOnce(Branded<'id, P, Relative<Add, _0>>, Len<'id>) -> R) -> R
+{
+	let len = Len{ len: anchor.len(), id: PhantomData };
+	f(Branded{ inner: anchor, len, constraint: PhantomData }, len)
+}
+
+
+/// The length of the anchor a `scope` was opened with, branded with the unique scope lifetime
+/// `'id`
+///
+/// _Note: A token can only be obtained from `scope` or from a value branded with it_
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Len<'id> {
+	len: usize,
+	id: Id<'id>
+}
+impl Len<'_> {
+	/// The branded length
+	pub fn get(self) -> usize {
+		self.len
+	}
+}
+
+
+/// A slice-like pointer `P` that was validated once against the branded length `Len<'id>` with the
+/// relative constraint `C`
+///
+/// Two values with the same `'id` thus carry a compile-time proof that their lengths relate via
+/// their constraints, so functions accepting them (e.g. `Branded<'id, &[u8], Relative<Add, _0>>`
+/// and `Branded<'id, &mut[u8], Relative<Add, _16>>`) need no further runtime checks.
+#[derive(Debug)]
+pub struct Branded<'id, P, C> {
+	inner: P,
+	len: Len<'id>,
+	constraint: PhantomData<C>
+}
+impl<'id, T, P: Deref<Target = [T]>, C: RelativeLenConstraint> Branded<'id, P, C> {
+	/// Validates `inner` against the branded length `len` and brands it on success
+	pub fn new(inner: P, len: Len<'id>) -> Result<Self, ConstraintViolation> {
+		Self::new_with(inner, len)
+	}
+	/// Like `new` but converts a constraint violation into the caller-chosen error `E`
+	pub fn new_with<E: From<ConstraintViolation>>(inner: P, len: Len<'id>) -> Result<Self, E> {
+		C::check(inner.len(), len.get())?;
+		Ok(Self{ inner, len, constraint: PhantomData })
+	}
+}
+impl<'id, P, C> Branded<'id, P, C> {
+	/// The branded length this value was validated against
+	pub fn len_token(&self) -> Len<'id> {
+		self.len
+	}
+	/// Returns the underlying slice pointer
+	pub fn into_inner(self) -> P {
+		self.inner
+	}
+	/// Drops the brand and returns the value as unvalidated relative constrained value
+	pub fn unbrand(self) -> Constrained<P, C> {
+		Constrained{ inner: self.inner, constraint: PhantomData }
+	}
+}
+impl<T, P: Deref<Target = [T]>, C> Branded<'_, P, C> {
+	/// The branded slice
+	pub fn slice(&self) -> &[T] {
+		&self.inner
+	}
+}
+impl<T, P: DerefMut<Target = [T]>, C> Branded<'_, P, C> {
+	/// The mutable branded slice
+	pub fn slice_mut(&mut self) -> &mut[T] {
+		&mut self.inner
+	}
+}
+impl<P: Clone, C> Clone for Branded<'_, P, C> {
+	fn clone(&self) -> Self {
+		Self{ inner: self.inner.clone(), len: self.len, constraint: PhantomData }
+	}
+}
+
+
+impl<'id, P: SliceRef, const N: usize> From<[Branded<'id, P, Relative<Add, _0>>; N]>
+	for SameLen<P, N>
+{
+	/// Creates a `SameLen` without revalidating the lengths because all slices share the brand
+	fn from(branded: [Branded<'id, P, Relative<Add, _0>>; N]) -> Self {
+		let len = branded.first().map_or(0, |branded| branded.len.get());
+		Self{ slices: branded.map(Branded::into_inner), len }
+	}
+}
@@ -0,0 +1,498 @@
+use crate::{
+	ConstraintViolation,
+	constraint::{
+		LenConstraint, RelativeLenConstraint, Fixed, Ranged,
+		Relative, RelativeAtLeast, RelativeAtMost, RelativeWithin, RelativeMulti
+	},
+	type_math::{ TypeNum, Operator, Reduction, AssertLessThan, AssertLessOrEqual }
+};
+use core::{
+	convert::TryFrom, marker::PhantomData,
+	ops::{ Deref, DerefMut }
+};
+#[cfg(feature = "alloc")]
+use alloc::{ borrow::Cow, boxed::Box, rc::Rc, sync::Arc, vec::Vec };
+
+
+/// Implements the conversion traits between a slice pointer type and its constrained variants
+macro_rules! impl_ptr {
+	($ptr:ty where $($impl_args:tt)*) => {
+		impl<$($impl_args)*, C: LenConstraint> Constrained<$ptr, C> {
+			/// Validates `inner` against the length constraint and creates the constrained value
+			pub fn new(inner: $ptr) -> Result<Self, ConstraintViolation> {
+				Self::try_from_with(inner)
+			}
+			/// Like `new` but converts a constraint violation into the caller-chosen error `E`
+			pub fn try_from_with<E: From<ConstraintViolation>>(inner: $ptr) -> Result<Self, E> {
+				C::check(inner.len())?;
+				Ok(Self{ inner, constraint: PhantomData })
+			}
+		}
+		impl<$($impl_args)*, C: LenConstraint> TryFrom<$ptr> for Constrained<$ptr, C> {
+			type Error = ConstraintViolation;
+			/// Validates `inner` against the length constraint and creates the constrained value
+			fn try_from(inner: $ptr) -> Result<Self, Self::Error> {
+				Self::new(inner)
+			}
+		}
+		impl_ptr!(@Into $ptr where $($impl_args)*);
+		impl_ptr!(@Relative $ptr where $($impl_args)*);
+	};
+	(@Into $ptr:ty where $($impl_args:tt)*) => {
+		impl<$($impl_args)*, C: LenConstraint> From<Constrained<$ptr, C>> for $ptr {
+			fn from(constrained: Constrained<$ptr, C>) -> Self {
+				constrained.inner
+			}
+		}
+	};
+	(@Relative $ptr:ty where $($impl_args:tt)*) => {
+		impl<$($impl_args)*, Op: Operator, By: TypeNum, Kind> From<$ptr>
+			for Constrained<$ptr, Relative<Op, By, Kind>>
+		{
+			/// Creates a new relative constrained value
+			fn from(inner: $ptr) -> Self {
+				Self::new(inner)
+			}
+		}
+	};
+}
+
+
+/// Implements the deconstruction functions for a relative constraint
+macro_rules! impl_relative {
+	($constraint:ty where $($impl_args:tt)*) => {
+		/// _Note: Unlike the other constraints, a relative constraint is not necessarily validated
+		/// on construction but on deconstruction (`self.slice(relative_to)`)_
+		impl<T, P: Deref<Target = [T]>, $($impl_args)*> Constrained<P, $constraint> {
+			/// Validates `inner` against the length constraint and creates the constrained value
+			/// with it
+			pub fn try_from(inner: P, relative_to: usize) -> Result<Self, ConstraintViolation> {
+				Self::try_from_with(inner, relative_to)
+			}
+			/// Like `try_from` but converts a constraint violation into the caller-chosen error `E`
+			pub fn try_from_with<E: From<ConstraintViolation>>(inner: P, relative_to: usize)
+				-> Result<Self, E>
+			{
+				<$constraint>::check(inner.len(), relative_to)?;
+				Ok(Self::new(inner))
+			}
+			
+			/// Computes the expected relative length from `relative_to`, validates the wrapped
+			/// slice against it and returns it on success
+			pub fn slice(self, relative_to: usize) -> Result<P, ConstraintViolation> {
+				self.slice_with(relative_to)
+			}
+			/// Like `slice` but converts a constraint violation into the caller-chosen error `E`
+			pub fn slice_with<E: From<ConstraintViolation>>(self, relative_to: usize)
+				-> Result<P, E>
+			{
+				<$constraint>::check(self.inner.len(), relative_to)?;
+				Ok(self.inner)
+			}
+			
+			/// Validates the wrapped slice against the length of `anchor` and returns both as
+			/// checked pair
+			pub fn relative_to<U, A: AsRef<[U]>>(self, anchor: A)
+				-> Result<Anchored<P, A, $constraint>, ConstraintViolation>
+			{
+				self.relative_to_with(anchor)
+			}
+			/// Like `relative_to` but converts a constraint violation into the caller-chosen error
+			/// `E`
+			pub fn relative_to_with<E: From<ConstraintViolation>, U, A: AsRef<[U]>>(self, anchor: A)
+				-> Result<Anchored<P, A, $constraint>, E>
+			{
+				<$constraint>::check(self.inner.len(), anchor.as_ref().len())?;
+				Ok(Anchored{ inner: self.inner, anchor, constraint: PhantomData })
+			}
+		}
+		impl<T, P: DerefMut<Target = [T]>, $($impl_args)*> Constrained<P, $constraint> {
+			/// Computes the expected relative length from `relative_to`, validates the wrapped
+			/// slice against it and returns it on success
+			pub fn slice_mut(self, relative_to: usize) -> Result<P, ConstraintViolation> {
+				self.slice(relative_to)
+			}
+			/// Like `slice_mut` but converts a constraint violation into the caller-chosen error
+			/// `E`
+			pub fn slice_mut_with<E: From<ConstraintViolation>>(self, relative_to: usize)
+				-> Result<P, E>
+			{
+				self.slice_with(relative_to)
+			}
+		}
+	};
+}
+
+
+/// Implements the prefix-splitting functions for a relative constraint with a lower bound
+macro_rules! impl_split {
+	($constraint:ident) => {
+		impl<'a, T, Op: Operator, By: TypeNum> Constrained<&'a[T], $constraint<Op, By>> {
+			/// Computes the expected relative length from `relative_to` and splits the wrapped
+			/// slice into a prefix of exactly that length and the unused tail
+			///
+			/// _Note: Unlike `slice`, this function accepts any slice that is at least as long as
+			/// the expected length_
+			pub fn split(self, relative_to: usize)
+				-> Result<(&'a[T], &'a[T]), ConstraintViolation>
+			{
+				let expected = Self::prefix_len(self.inner.len(), relative_to)?;
+				Ok(self.inner.split_at(expected))
+			}
+		}
+		impl<'a, T, Op: Operator, By: TypeNum> Constrained<&'a mut[T], $constraint<Op, By>> {
+			/// Computes the expected relative length from `relative_to` and splits the wrapped
+			/// slice into a prefix of exactly that length and the unused tail
+			///
+			/// _Note: Unlike `slice_mut`, this function accepts any slice that is at least as long
+			/// as the expected length_
+			pub fn split_mut(self, relative_to: usize)
+				-> Result<(&'a mut[T], &'a mut[T]), ConstraintViolation>
+			{
+				let expected = Self::prefix_len(self.inner.len(), relative_to)?;
+				Ok(self.inner.split_at_mut(expected))
+			}
+		}
+		impl<P, Op: Operator, By: TypeNum> Constrained<P, $constraint<Op, By>> {
+			/// Validates that `len` is at least `Op(relative_to, By)` and returns the latter
+			fn prefix_len(len: usize, relative_to: usize) -> Result<usize, ConstraintViolation> {
+				let () = <$constraint<Op, By>>::ASSERT;
+				let violation = ConstraintViolation::relative_at_least::<Op, By>(len, relative_to);
+				if let Some(e) = violation {
+					return Err(e)
+				}
+				Op::r#do(relative_to, By::VALUE)
+			}
+		}
+	};
+}
+
+
+/// Implements the inverse functions for a relative constraint
+macro_rules! impl_inverse {
+	($constraint:ident) => {
+		impl<T, P: Deref<Target = [T]>, Op: Operator, By: TypeNum>
+			Constrained<P, $constraint<Op, By>>
+		{
+			/// The largest `relative_to` where `Op(relative_to, By)` does not exceed the length of
+			/// the wrapped slice (e.g. the largest plaintext an output buffer can hold) or `None`
+			/// if there is none
+			///
+			/// _Note: This rounds down if the operator is not exactly invertible (see
+			/// `Operator::inverse_floor`)_
+			pub fn max_relative_to(&self) -> Option<usize> {
+				let () = <$constraint<Op, By>>::ASSERT;
+				Op::inverse_floor(self.inner.len(), By::VALUE)
+			}
+			/// The smallest `relative_to` where `Op(relative_to, By)` reaches the length of the
+			/// wrapped slice (e.g. the input length a buffer implies) or `None` if there is none
+			///
+			/// _Note: This rounds up if the operator is not exactly invertible (see
+			/// `Operator::inverse_ceil`)_
+			pub fn required_relative_to(&self) -> Option<usize> {
+				let () = <$constraint<Op, By>>::ASSERT;
+				Op::inverse_ceil(self.inner.len(), By::VALUE)
+			}
+		}
+	};
+}
+
+
+/// A slice-like pointer `P` (e.g. `&[T]`, `&mut[T]`, `Vec<T>`, `Box<[T]>`, `Rc<[T]>`, `Arc<[T]>`
+/// or `Cow<[T]>`) with `C` as length constraint
+///
+/// _Note: The conversions for the heap-allocated pointer types require the `alloc` feature_
+///
+/// _Note: The constraint types live in the `constraint` module; the `slice`, `slice_mut` and
+/// `owned` modules provide aliases for the most common combinations_
+#[derive(Debug)]
+pub struct Constrained<P, C> {
+	pub(crate) inner: P,
+	pub(crate) constraint: PhantomData<C>
+}
+impl<P: Clone, C> Clone for Constrained<P, C> {
+	fn clone(&self) -> Self {
+		Self{ inner: self.inner.clone(), constraint: PhantomData }
+	}
+}
+impl<P: Copy, C> Copy for Constrained<P, C> {}
+
+
+/// A relative constrained slice-like pointer `P` together with the anchor `A` it was validated
+/// against (see `relative_to`)
+///
+/// _Note: This type can only be created by a successful validation, so it proves that both values
+/// were checked together_
+#[derive(Debug)]
+pub struct Anchored<P, A, C> {
+	inner: P,
+	anchor: A,
+	constraint: PhantomData<C>
+}
+impl<P, A, C> Anchored<P, A, C> {
+	/// The anchor the constrained value was validated against
+	pub fn anchor(&self) -> &A {
+		&self.anchor
+	}
+	/// Returns the underlying slice pointer and the anchor
+	pub fn into_inner(self) -> (P, A) {
+		(self.inner, self.anchor)
+	}
+}
+impl<T, P: Deref<Target = [T]>, A, C> Anchored<P, A, C> {
+	/// The constrained slice
+	pub fn slice(&self) -> &[T] {
+		&self.inner
+	}
+}
+impl<T, P: DerefMut<Target = [T]>, A, C> Anchored<P, A, C> {
+	/// The mutable constrained slice
+	pub fn slice_mut(&mut self) -> &mut[T] {
+		&mut self.inner
+	}
+}
+
+
+impl<T, P: Deref<Target = [T]>, C: LenConstraint> Constrained<P, C> {
+	/// The length of the constrained slice
+	pub fn len(&self) -> usize {
+		self.inner.len()
+	}
+	/// Whether the constrained slice is empty or not
+	pub fn is_empty(&self) -> bool {
+		self.inner.is_empty()
+	}
+	/// The constrained slice
+	pub fn slice(&self) -> &[T] {
+		&self.inner
+	}
+	/// Lends the constrained slice out as constrained slice reference
+	pub fn as_constrained(&self) -> Constrained<&[T], C> {
+		Constrained{ inner: &self.inner, constraint: PhantomData }
+	}
+	/// Returns the underlying slice pointer
+	pub fn into_inner(self) -> P {
+		self.inner
+	}
+}
+impl<T, P: DerefMut<Target = [T]>, C: LenConstraint> Constrained<P, C> {
+	/// The mutable constrained slice
+	pub fn slice_mut(&mut self) -> &mut[T] {
+		&mut self.inner
+	}
+	/// Lends the constrained slice out as mutable constrained slice reference
+	pub fn as_constrained_mut(&mut self) -> Constrained<&mut[T], C> {
+		Constrained{ inner: &mut self.inner, constraint: PhantomData }
+	}
+}
+impl<T, P: Deref<Target = [T]>, C: LenConstraint> Deref for Constrained<P, C> {
+	type Target = [T];
+	fn deref(&self) -> &Self::Target {
+		&self.inner
+	}
+}
+impl<T, P: DerefMut<Target = [T]>, C: LenConstraint> DerefMut for Constrained<P, C> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.inner
+	}
+}
+impl<T, P: Deref<Target = [T]>, C: LenConstraint> AsRef<[T]> for Constrained<P, C> {
+	fn as_ref(&self) -> &[T] {
+		&self.inner
+	}
+}
+impl<T, P: DerefMut<Target = [T]>, C: LenConstraint> AsMut<[T]> for Constrained<P, C> {
+	fn as_mut(&mut self) -> &mut[T] {
+		&mut self.inner
+	}
+}
+
+
+impl<T, P: Deref<Target = [T]>, Val: TypeNum> Constrained<P, Fixed<Val>> {
+	/// Lends the constrained slice out as constrained slice reference
+	pub fn as_fixed(&self) -> Constrained<&[T], Fixed<Val>> {
+		self.as_constrained()
+	}
+}
+impl<T, P: DerefMut<Target = [T]>, Val: TypeNum> Constrained<P, Fixed<Val>> {
+	/// Lends the constrained slice out as mutable constrained slice reference
+	pub fn as_fixed_mut(&mut self) -> Constrained<&mut[T], Fixed<Val>> {
+		self.as_constrained_mut()
+	}
+}
+
+
+
+impl<T, P: Deref<Target = [T]>, Start: TypeNum, End: TypeNum> Constrained<P, Ranged<Start, End>> {
+	/// Lends the constrained slice out as constrained slice reference
+	pub fn as_ranged(&self) -> Constrained<&[T], Ranged<Start, End>> {
+		self.as_constrained()
+	}
+}
+impl<P, Val: TypeNum> Constrained<P, Fixed<Val>> {
+	/// Converts the fixed constraint into a range `Start..End` that contains it
+	///
+	/// _Note: This is not a `From` conversion because the trait system would then advertise it for
+	/// ranges that do not contain `Val`; the conversion fails to compile if `Start <= Val < End`
+	/// does not hold. The check is evaluated after monomorphization, so it is only reported by a
+	/// full build (not by `cargo check`) and the error points into this crate instead of the
+	/// caller_
+	///
+	/// ```compile_fail
+	/// # use len_constraints::{ slice::Fixed, type_math::{ _8, _16, _64 } };
+	/// # use std::convert::TryFrom;
+	/// let fixed = Fixed::<u8, _64>::try_from([0; 64].as_ref()).unwrap();
+	/// let _ranged = fixed.into_ranged::<_8, _16>();
+	/// ```
+	pub fn into_ranged<Start: TypeNum, End: TypeNum>(self) -> Constrained<P, Ranged<Start, End>> {
+		let () = AssertLessOrEqual::<Start, Val>::ASSERT;
+		let () = AssertLessThan::<Val, End>::ASSERT;
+		Constrained{ inner: self.inner, constraint: PhantomData }
+	}
+}
+impl<P, Start: TypeNum, End: TypeNum> Constrained<P, Ranged<Start, End>> {
+	/// Converts the range into a wider range `To` that contains it
+	///
+	/// _Note: This is not a `From` conversion because it would conflict with `From<T> for T`; the
+	/// conversion fails to compile if `ToStart <= Start` and `End <= ToEnd` do not hold. The check
+	/// is evaluated after monomorphization, so it is only reported by a full build (not by
+	/// `cargo check`) and the error points into this crate instead of the caller_
+	///
+	/// ```compile_fail
+	/// # use len_constraints::{ slice::Ranged, type_math::{ _8, _16, _32, _64 } };
+	/// # use std::convert::TryFrom;
+	/// let ranged = Ranged::<u8, _8, _64>::try_from([0; 8].as_ref()).unwrap();
+	/// let _narrowed = ranged.widen::<_16, _32>();
+	/// ```
+	pub fn widen<ToStart: TypeNum, ToEnd: TypeNum>(self) -> Constrained<P, Ranged<ToStart, ToEnd>> {
+		let () = AssertLessOrEqual::<ToStart, Start>::ASSERT;
+		let () = AssertLessOrEqual::<End, ToEnd>::ASSERT;
+		Constrained{ inner: self.inner, constraint: PhantomData }
+	}
+}
+impl<T, P: DerefMut<Target = [T]>, Start: TypeNum, End: TypeNum>
+	Constrained<P, Ranged<Start, End>>
+{
+	/// Lends the constrained slice out as mutable constrained slice reference
+	pub fn as_ranged_mut(&mut self) -> Constrained<&mut[T], Ranged<Start, End>> {
+		self.as_constrained_mut()
+	}
+}
+
+
+/// _Note: Unlike the other constraints, a relative constraint is not necessarily validated on
+/// construction but on deconstruction (`self.slice(relative_to)`)_
+impl<T, P: Deref<Target = [T]>, Op: Operator, By: TypeNum, Kind>
+	Constrained<P, Relative<Op, By, Kind>>
+{
+	/// Creates a new relative constrained value without validating it
+	pub fn new(inner: P) -> Self {
+		let () = Relative::<Op, By, Kind>::ASSERT;
+		Self{ inner, constraint: PhantomData }
+	}
+}
+impl_relative!(Relative<Op, By> where Op: Operator, By: TypeNum);
+impl_relative!(RelativeAtLeast<Op, By> where Op: Operator, By: TypeNum);
+impl_relative!(RelativeAtMost<Op, By> where Op: Operator, By: TypeNum);
+impl_relative!(
+	RelativeWithin<Op, By, Tolerance> where Op: Operator, By: TypeNum, Tolerance: TypeNum
+);
+/// _Note: Like the other relative constraints, this constraint is not necessarily validated on
+/// construction but on deconstruction (`self.slice(relative_to)`)_
+impl<T, P: Deref<Target = [T]>, R: Reduction, Op: Operator, By: TypeNum>
+	Constrained<P, RelativeMulti<R, Op, By>>
+{
+	/// Validates `inner` against the length constraint and creates the constrained value with it
+	pub fn try_from<const N: usize>(inner: P, relative_to: [usize; N])
+		-> Result<Self, ConstraintViolation>
+	{
+		Self::try_from_with(inner, relative_to)
+	}
+	/// Like `try_from` but converts a constraint violation into the caller-chosen error `E`
+	pub fn try_from_with<E: From<ConstraintViolation>, const N: usize>(inner: P,
+		relative_to: [usize; N]) -> Result<Self, E>
+	{
+		RelativeMulti::<R, Op, By>::check(inner.len(), relative_to)?;
+		Ok(Self::new(inner))
+	}
+	
+	/// Computes the expected relative length from all lengths in `relative_to`, validates the
+	/// wrapped slice against it and returns it on success
+	pub fn slice<const N: usize>(self, relative_to: [usize; N]) -> Result<P, ConstraintViolation> {
+		self.slice_with(relative_to)
+	}
+	/// Like `slice` but converts a constraint violation into the caller-chosen error `E`
+	pub fn slice_with<E: From<ConstraintViolation>, const N: usize>(self, relative_to: [usize; N])
+		-> Result<P, E>
+	{
+		RelativeMulti::<R, Op, By>::check(self.inner.len(), relative_to)?;
+		Ok(self.inner)
+	}
+}
+impl<T, P: DerefMut<Target = [T]>, R: Reduction, Op: Operator, By: TypeNum>
+	Constrained<P, RelativeMulti<R, Op, By>>
+{
+	/// Computes the expected relative length from all lengths in `relative_to`, validates the
+	/// wrapped slice against it and returns it on success
+	pub fn slice_mut<const N: usize>(self, relative_to: [usize; N])
+		-> Result<P, ConstraintViolation>
+	{
+		self.slice(relative_to)
+	}
+	/// Like `slice_mut` but converts a constraint violation into the caller-chosen error `E`
+	pub fn slice_mut_with<E: From<ConstraintViolation>, const N: usize>(self,
+		relative_to: [usize; N]) -> Result<P, E>
+	{
+		self.slice_with(relative_to)
+	}
+}
+
+
+impl_split!(Relative);
+impl_split!(RelativeAtLeast);
+impl_inverse!(Relative);
+impl_inverse!(RelativeAtLeast);
+impl_inverse!(RelativeAtMost);
+
+
+impl_ptr!(&'a[T] where 'a, T);
+impl_ptr!(&'a mut[T] where 'a, T);
+#[cfg(feature = "alloc")]
+impl_ptr!(Box<[T]> where T);
+#[cfg(feature = "alloc")]
+impl_ptr!(Rc<[T]> where T);
+#[cfg(feature = "alloc")]
+impl_ptr!(Arc<[T]> where T);
+#[cfg(feature = "alloc")]
+impl_ptr!(Cow<'a, [T]> where 'a, T: Clone);
+#[cfg(feature = "alloc")]
+impl_ptr!(@Into Vec<T> where T);
+#[cfg(feature = "alloc")]
+impl_ptr!(@Relative Vec<T> where T);
+#[cfg(feature = "alloc")]
+impl<T, C: LenConstraint> Constrained<Vec<T>, C> {
+	/// Validates `vec` against the length constraint and creates the constrained vector with it
+	pub fn new(vec: Vec<T>) -> Result<Self, ConstraintViolation> {
+		Self::try_from_with(vec)
+	}
+	/// Like `new` but converts a constraint violation into the caller-chosen error `E`
+	///
+	/// _Note: Every constrained vector is created by this function; it also tries to reserve enough
+	/// capacity to hold the largest valid length if the constraint provides a capacity hint and
+	/// skips the reservation if that allocation fails_
+	pub fn try_from_with<E: From<ConstraintViolation>>(mut vec: Vec<T>) -> Result<Self, E> {
+		C::check(vec.len())?;
+		if let Some(capacity) = C::capacity_hint() {
+			let _ = vec.try_reserve_exact(capacity.saturating_sub(vec.len()));
+		}
+		Ok(Self{ inner: vec, constraint: PhantomData })
+	}
+}
+#[cfg(feature = "alloc")]
+impl<T, C: LenConstraint> TryFrom<Vec<T>> for Constrained<Vec<T>, C> {
+	type Error = ConstraintViolation;
+	/// Validates `vec` against the length constraint and creates the constrained vector with it
+	fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+		Self::new(vec)
+	}
+}
@@ -0,0 +1,253 @@
+use crate::{
+	ConstraintViolation,
+	type_math::{ TypeNum, Operator, Reduction }
+};
+use core::{
+	marker::PhantomData,
+	fmt::{ self, Debug, Display, Formatter }
+};
+
+
+/// A type representing a length constraint that can be validated on its own
+///
+/// Implement this trait to add your own constraint kinds; `Constrained` and the growable containers
+/// are generic over it.
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` is not an absolute length constraint",
+	label = "expected a length constraint like `Fixed` or `Ranged`",
+	note = "relative constraints are validated with `slice(relative_to)` instead"
+)]
+pub trait LenConstraint: Debug + Default {
+	/// Checks whether `len` satisfies the constraint
+	fn check(len: usize) -> Result<(), ConstraintViolation>;
+	
+	/// The largest length that satisfies the constraint if it is reasonable to preallocate it
+	fn capacity_hint() -> Option<usize> {
+		None
+	}
+}
+
+/// A type representing a length constraint that is validated relative to another length
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` is not a relative length constraint",
+	label = "expected a relative length constraint like `Relative`"
+)]
+pub trait RelativeLenConstraint: Debug + Default {
+	/// Checks whether `len` satisfies the constraint relative to `relative_to`
+	fn check(len: usize, relative_to: usize) -> Result<(), ConstraintViolation>;
+}
+
+
+/// A fixed length as length constraint
+#[derive(Debug, Default)]
+pub struct Fixed<Val: TypeNum>(PhantomData<Val>);
+impl<Val: TypeNum> LenConstraint for Fixed<Val> {
+	fn check(len: usize) -> Result<(), ConstraintViolation> {
+		ConstraintViolation::fixed::<Val>(len).map_or(Ok(()), Err)
+	}
+	fn capacity_hint() -> Option<usize> {
+		Some(Val::VALUE)
+	}
+}
+
+/// A range (`Start <= len < End`) as length constraint
+#[derive(Debug, Default)]
+pub struct Ranged<Start: TypeNum, End: TypeNum>(PhantomData<(Start, End)>);
+impl<Start: TypeNum, End: TypeNum> Ranged<Start, End> {
+	/// Fails to compile if the range is empty (i.e. `Start >= End`) and thus rejects every length
+	///
+	/// ```compile_fail
+	/// # use len_constraints::{ constraint::{ LenConstraint, Ranged }, type_math::{ _16, _64 } };
+	/// Ranged::<_64, _16>::check(32);
+	/// ```
+	pub const ASSERT: () = assert!(Start::VALUE < End::VALUE, "the range `Start..End` is empty");
+}
+impl<Start: TypeNum, End: TypeNum> LenConstraint for Ranged<Start, End> {
+	fn check(len: usize) -> Result<(), ConstraintViolation> {
+		let () = Self::ASSERT;
+		ConstraintViolation::ranged::<Start, End>(len).map_or(Ok(()), Err)
+	}
+	fn capacity_hint() -> Option<usize> {
+		End::VALUE.checked_sub(1)
+	}
+}
+
+/// An inclusive range (`Start <= len <= End`) as length constraint
+///
+/// _Note: Unlike `Ranged`, this constraint does not preallocate its upper bound because it is
+/// usually a loose limit_
+#[derive(Debug, Default)]
+pub struct RangedInclusive<Start: TypeNum, End: TypeNum>(PhantomData<(Start, End)>);
+impl<Start: TypeNum, End: TypeNum> RangedInclusive<Start, End> {
+	/// Fails to compile if the range is empty (i.e. `Start > End`) and thus rejects every length
+	///
+	/// ```compile_fail
+	/// # use len_constraints::{
+	/// # 	constraint::{ LenConstraint, RangedInclusive }, type_math::{ _16, _64 }
+	/// # };
+	/// RangedInclusive::<_64, _16>::check(32);
+	/// ```
+	pub const ASSERT: () = assert!(Start::VALUE <= End::VALUE, "the range `Start..=End` is empty");
+}
+impl<Start: TypeNum, End: TypeNum> LenConstraint for RangedInclusive<Start, End> {
+	fn check(len: usize) -> Result<(), ConstraintViolation> {
+		let () = Self::ASSERT;
+		ConstraintViolation::ranged_inclusive::<Start, End>(len).map_or(Ok(()), Err)
+	}
+}
+
+/// A lower bound (`len >= Min`) as length constraint
+#[derive(Debug, Default)]
+pub struct AtLeast<Min: TypeNum>(PhantomData<Min>);
+impl<Min: TypeNum> LenConstraint for AtLeast<Min> {
+	fn check(len: usize) -> Result<(), ConstraintViolation> {
+		ConstraintViolation::at_least::<Min>(len).map_or(Ok(()), Err)
+	}
+}
+
+/// An upper bound (`len <= Max`) as length constraint
+///
+/// _Note: This constraint does not preallocate `Max` because it is usually a loose limit_
+#[derive(Debug, Default)]
+pub struct AtMost<Max: TypeNum>(PhantomData<Max>);
+impl<Max: TypeNum> LenConstraint for AtMost<Max> {
+	fn check(len: usize) -> Result<(), ConstraintViolation> {
+		ConstraintViolation::at_most::<Max>(len).map_or(Ok(()), Err)
+	}
+}
+
+/// A relative length (`len == Op(relative_to, By)`) as length constraint
+///
+/// _Note: `Kind` selects how `len` relates to `Op(relative_to, By)`; the other relative constraints
+/// (e.g. `RelativeAtLeast`) are aliases of this type with another kind from the `relative` module_
+#[derive(Default)]
+pub struct Relative<Op: Operator, By: TypeNum, Kind = relative::Exact>(
+	PhantomData<(Op, By, Kind)>
+);
+impl<Op: Operator, By: TypeNum, Kind> Relative<Op, By, Kind> {
+	/// Fails to compile if `Op` rejects `By` as right-hand operand (e.g. a division by zero)
+	///
+	/// ```compile_fail
+	/// # use len_constraints::{ slice::Relative, type_math::{ Div, _0 } };
+	/// Relative::<u8, Div, _0>::new(&[]);
+	/// ```
+	pub const ASSERT: () = assert!(
+		!(Op::REJECTS_ZERO && By::VALUE == 0),
+		"the operator `Op` does not accept zero as `By`"
+	);
+}
+impl<Op: Operator, By: TypeNum> Debug for Relative<Op, By> {
+	/// Writes the constraint as formula (e.g. `len == relative_to + 16`)
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "len == ")?;
+		Op::fmt_formula(f, &"relative_to", &By::VALUE)
+	}
+}
+impl<Op: Operator, By: TypeNum> RelativeLenConstraint for Relative<Op, By> {
+	fn check(len: usize, relative_to: usize) -> Result<(), ConstraintViolation> {
+		let () = Self::ASSERT;
+		ConstraintViolation::relative::<Op, By>(len, relative_to).map_or(Ok(()), Err)
+	}
+}
+
+/// A minimum relative length (`len >= Op(relative_to, By)`) as length constraint
+pub type RelativeAtLeast<Op, By> = Relative<Op, By, relative::AtLeast>;
+impl<Op: Operator, By: TypeNum> Debug for RelativeAtLeast<Op, By> {
+	/// Writes the constraint as formula (e.g. `len >= relative_to + 16`)
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "len >= ")?;
+		Op::fmt_formula(f, &"relative_to", &By::VALUE)
+	}
+}
+impl<Op: Operator, By: TypeNum> RelativeLenConstraint for RelativeAtLeast<Op, By> {
+	fn check(len: usize, relative_to: usize) -> Result<(), ConstraintViolation> {
+		let () = Self::ASSERT;
+		ConstraintViolation::relative_at_least::<Op, By>(len, relative_to).map_or(Ok(()), Err)
+	}
+}
+
+/// A maximum relative length (`len <= Op(relative_to, By)`) as length constraint
+pub type RelativeAtMost<Op, By> = Relative<Op, By, relative::AtMost>;
+impl<Op: Operator, By: TypeNum> Debug for RelativeAtMost<Op, By> {
+	/// Writes the constraint as formula (e.g. `len <= relative_to + 16`)
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "len <= ")?;
+		Op::fmt_formula(f, &"relative_to", &By::VALUE)
+	}
+}
+impl<Op: Operator, By: TypeNum> RelativeLenConstraint for RelativeAtMost<Op, By> {
+	fn check(len: usize, relative_to: usize) -> Result<(), ConstraintViolation> {
+		let () = Self::ASSERT;
+		ConstraintViolation::relative_at_most::<Op, By>(len, relative_to).map_or(Ok(()), Err)
+	}
+}
+
+/// A relative length with a tolerance (`|len - Op(relative_to, By)| <= Tolerance`) as length
+/// constraint
+pub type RelativeWithin<Op, By, Tolerance> = Relative<Op, By, relative::Within<Tolerance>>;
+impl<Op: Operator, By: TypeNum, Tolerance: TypeNum> Debug for RelativeWithin<Op, By, Tolerance> {
+	/// Writes the constraint as formula (e.g. `|len - (relative_to + 16)| <= 4`)
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "|len - (")?;
+		Op::fmt_formula(f, &"relative_to", &By::VALUE)?;
+		write!(f, ")| <= {}", Tolerance::VALUE)
+	}
+}
+impl<Op: Operator, By: TypeNum, Tolerance: TypeNum> RelativeLenConstraint
+	for RelativeWithin<Op, By, Tolerance>
+{
+	fn check(len: usize, relative_to: usize) -> Result<(), ConstraintViolation> {
+		let () = Self::ASSERT;
+		ConstraintViolation::relative_within::<Op, By, Tolerance>(len, relative_to)
+			.map_or(Ok(()), Err)
+	}
+}
+
+/// A relative length over multiple lengths (`len == Op(R(relative_to), By)`) as length constraint
+/// (e.g. `len == sum(relative_to) + 16` for a concatenated and authenticated envelope)
+pub type RelativeMulti<R, Op, By> = Relative<Op, By, relative::Multi<R>>;
+impl<R: Reduction, Op: Operator, By: TypeNum> RelativeMulti<R, Op, By> {
+	/// Checks whether `len` satisfies the constraint relative to all lengths in `relative_to`
+	pub fn check<const N: usize>(len: usize, relative_to: [usize; N])
+		-> Result<(), ConstraintViolation>
+	{
+		let () = Self::ASSERT;
+		ConstraintViolation::relative_multi::<R, Op, By, N>(len, relative_to).map_or(Ok(()), Err)
+	}
+}
+impl<R: Reduction, Op: Operator, By: TypeNum> Debug for RelativeMulti<R, Op, By> {
+	/// Writes the constraint as formula (e.g. `len == sum(relative_to) + 16`)
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "len == ")?;
+		Op::fmt_formula(f, &Reduced::<R>(PhantomData), &By::VALUE)
+	}
+}
+/// The reduced `relative_to` operand of a formula
+struct Reduced<R: Reduction>(PhantomData<R>);
+impl<R: Reduction> Display for Reduced<R> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		R::fmt_formula(f, &"relative_to")
+	}
+}
+
+
+/// The kinds of the `Relative` constraint
+pub mod relative {
+	use core::marker::PhantomData;
+	
+	/// `len == Op(relative_to, By)` (see `Relative`)
+	#[derive(Debug, Default)]
+	pub struct Exact;
+	/// `len >= Op(relative_to, By)` (see `RelativeAtLeast`)
+	#[derive(Debug, Default)]
+	pub struct AtLeast;
+	/// `len <= Op(relative_to, By)` (see `RelativeAtMost`)
+	#[derive(Debug, Default)]
+	pub struct AtMost;
+	/// `|len - Op(relative_to, By)| <= Tolerance` (see `RelativeWithin`)
+	#[derive(Debug, Default)]
+	pub struct Within<Tolerance>(PhantomData<Tolerance>);
+	/// `len == Op(R(relative_to), By)` (see `RelativeMulti`)
+	#[derive(Debug, Default)]
+	pub struct Multi<R>(PhantomData<R>);
+}
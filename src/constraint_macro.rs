@@ -10,15 +10,15 @@
 /// };
 ///
 /// // Print the types
-/// println!("{:?}", buf);       // RelativeMut { slice: [ ... ], constraint: PhantomData }
-/// println!("{:?}", plaintext); // Ranged { slice: [ ... ], constraint: PhantomData }
-/// println!("{:?}", key);       // Fixed { slice: [ ... ], constraint: PhantomData }
-/// println!("{:?}", nonce);     // Fixed { slice: [ ... ], constraint: PhantomData }
+/// println!("{:?}", buf);       // Constrained { inner: [ ... ], constraint: PhantomData<..> }
+/// println!("{:?}", plaintext); // Constrained { inner: [ ... ], constraint: PhantomData<..> }
+/// println!("{:?}", key);       // Constrained { inner: [ ... ], constraint: PhantomData<..> }
+/// println!("{:?}", nonce);     // Constrained { inner: [ ... ], constraint: PhantomData<..> }
 /// ```
 #[macro_export]
 macro_rules! constraints {
 	($source:ident => $constrained:ty) => {
-		let mut $source: $constrained = ::std::convert::TryFrom::try_from($source)?;
+		let mut $source: $constrained = ::core::convert::TryFrom::try_from($source)?;
 	};
 	($source:ident => $constrained:ty [$len:expr]) => {
 		let mut $source = <$constrained>::try_from($source, $len)?;
@@ -0,0 +1,58 @@
+use crate::type_math::TypeNum;
+use core::fmt::Debug;
+use typenum::{ Bit, Unsigned, UInt, UTerm };
+
+
+impl TypeNum for UTerm {
+	const VALUE: usize = 0;
+}
+impl<U: Unsigned + Debug + Default, B: Bit + Debug + Default> TypeNum for UInt<U, B> {
+	const VALUE: usize = <Self as Unsigned>::USIZE;
+}
+
+
+#[cfg(feature = "generic-array")]
+mod generic_array_impl {
+	use crate::{ Constrained, constraint::Fixed, type_math::{ TypeNum, ArrayLen } };
+	use core::marker::PhantomData;
+	use generic_array::{ ArrayLength, GenericArray, sequence::GenericSequence };
+
+
+	impl<T, N: TypeNum + ArrayLength> ArrayLen<T> for N {
+		type Array = GenericArray<T, N>;
+		fn array_from_fn<F: FnMut(usize) -> T>(f: F) -> Self::Array {
+			GenericArray::generate(f)
+		}
+	}
+
+
+	impl<'a, T, N: TypeNum + ArrayLength> From<&'a GenericArray<T, N>>
+		for Constrained<&'a[T], Fixed<N>>
+	{
+		fn from(array: &'a GenericArray<T, N>) -> Self {
+			Self{ inner: array.as_slice(), constraint: PhantomData }
+		}
+	}
+	impl<'a, T, N: TypeNum + ArrayLength> From<Constrained<&'a[T], Fixed<N>>>
+		for &'a GenericArray<T, N>
+	{
+		fn from(constrained: Constrained<&'a[T], Fixed<N>>) -> Self {
+			GenericArray::from_slice(constrained.inner)
+		}
+	}
+	
+	impl<'a, T, N: TypeNum + ArrayLength> From<&'a mut GenericArray<T, N>>
+		for Constrained<&'a mut[T], Fixed<N>>
+	{
+		fn from(array: &'a mut GenericArray<T, N>) -> Self {
+			Self{ inner: array.as_mut_slice(), constraint: PhantomData }
+		}
+	}
+	impl<'a, T, N: TypeNum + ArrayLength> From<Constrained<&'a mut[T], Fixed<N>>>
+		for &'a mut GenericArray<T, N>
+	{
+		fn from(constrained: Constrained<&'a mut[T], Fixed<N>>) -> Self {
+			GenericArray::from_mut_slice(constrained.inner)
+		}
+	}
+}
@@ -46,14 +46,14 @@
 //! ```
 //! // GOOD EXAMPLE :D
 //!
-//! use std::{ convert::TryInto, error::Error };
+//! use std::convert::TryInto;
 //! use len_constraints::{
-//! 	slice_mut::RelativeMut, slice::{ Fixed, Ranged },
+//! 	ConstraintViolation, slice_mut::RelativeMut, slice::{ Fixed, Ranged },
 //! 	type_math::{ Add, _0, _12, _16, _32, _65536 }
 //! };
 //!
 //! fn encrypt(buf: RelativeMut<u8, Add, _16>, plaintext: Ranged<u8, _0, _65536>,
-//! 	key: Fixed<u8, _32>, nonce: Fixed<u8, _12>) -> Result<usize, Box<Error + 'static>>
+//! 	key: Fixed<u8, _32>, nonce: Fixed<u8, _12>) -> Result<usize, ConstraintViolation>
 //! {
 //! 	// Get buffer (we do this here because there may not be a relationship at an earlier stage)
 //! 	let buf = buf.slice_mut(plaintext.len())?;
@@ -62,7 +62,7 @@
 //! 	Ok(7)
 //! }
 //!
-//! fn main() -> Result<(), Box<Error + 'static>> {
+//! fn main() -> Result<(), ConstraintViolation> {
 //! 	// Parameters
 //! 	let mut buf: &mut[u8] = &mut[0; 9 + 16];
 //! 	let plaintext: &[u8] = b"Testolope";
@@ -76,79 +76,501 @@
 //! ```
 //! As you can see, we now can describe complex relationships in the function signature – this makes
 //! the API more transparent and removes the need for manual (and error-prone) parameter validation.
+//!
+//!
+//! ## Features
+//! - `std` _(default)_: Implements `std::error::Error` for `ConstraintViolation` and the conversion
+//!   into `std::io::Error`; implies `alloc`
+//! - `alloc`: Adds the heap-allocated containers (`Vec`, `Box`, `Rc`, `Arc`, `Cow`)
+//! - `typenum`: Implements `TypeNum` for the unsigned integers of the `typenum` crate
+//! - `generic-array`: Adds zero-copy conversions between `slice::Fixed`/`slice_mut::FixedMut` and
+//!   `GenericArray` references and uses `GenericArray` as inline storage; implies `typenum`
+//!
+//! Without any feature, the crate only depends on `core`.
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::tabs_in_doc_comments)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 
 /// The `TypeNum` and `Operator` traits which can be used as type arguments as well as some
 /// predefined numbers and operators.
 #[macro_use] pub mod type_math;
+/// The `LenConstraint` and `RelativeLenConstraint` traits as well as the predefined constraint
+/// types that can be used with `Constrained`
+pub mod constraint;
+/// A generic wrapper for slice-like pointers with a length constraint
+pub mod constrained;
 /// Some wrappers for immutable slices with various length constraints
 pub mod slice;
 /// Some wrappers for mutable slices with various length constraints
 pub mod slice_mut;
+/// Some owned containers with various length constraints
+pub mod owned;
+/// A container for multiple slices that are validated to have the same length
+pub mod same_len;
+/// Branded lengths that prove relations between slices at compile time
+pub mod branded;
 #[macro_use] mod constraint_macro;
+#[cfg(feature = "typenum")]
+mod interop;
 
-pub use self::type_math::{ TypeNum, Operator };
-use std::{
-	convert::TryFrom, error::Error,
-	fmt::{ self, Display, Formatter }
+pub use self::{
+	constrained::{ Constrained, Anchored },
+	type_math::{ TypeNum, Operator, Reduction }
 };
+use crate::constraint::{ Relative, RelativeAtLeast, RelativeAtMost, RelativeWithin, RelativeMulti };
+use core::fmt::{ self, Debug, Display, Formatter };
+#[cfg(feature = "std")]
+use std::{ io, error::Error };
 
 
-/// A constraint violation error
-#[derive(Debug)]
-pub struct ConstraintViolation {
-	#[doc(hidden)]
-	pub constraint: String,
-	#[doc(hidden)]
-	pub by: i128
+/// A constraint violation or arithmetic error
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConstraintViolation {
+	/// A fixed length constraint (`actual == expected`) was violated
+	Fixed {
+		/// The expected length
+		expected: usize,
+		/// The actual length
+		actual: usize
+	},
+	/// A range constraint (`start <= actual < end`) was violated
+	Ranged {
+		/// The inclusive lower bound
+		start: usize,
+		/// The exclusive upper bound
+		end: usize,
+		/// The actual length
+		actual: usize
+	},
+	/// An inclusive range constraint (`start <= actual <= end`) was violated
+	RangedInclusive {
+		/// The inclusive lower bound
+		start: usize,
+		/// The inclusive upper bound
+		end: usize,
+		/// The actual length
+		actual: usize
+	},
+	/// A lower bound constraint (`actual >= min`) was violated
+	AtLeast {
+		/// The inclusive lower bound
+		min: usize,
+		/// The actual length
+		actual: usize
+	},
+	/// An upper bound constraint (`actual <= max`) was violated
+	AtMost {
+		/// The inclusive upper bound
+		max: usize,
+		/// The actual length
+		actual: usize
+	},
+	/// A relative constraint (`actual == Op(relative_to, By)`) was violated
+	Relative {
+		/// The expected length computed from `relative_to`
+		expected: usize,
+		/// The actual length
+		actual: usize,
+		/// The length the expected length was computed from
+		relative_to: usize,
+		/// The formula of the violated constraint
+		formula: Formula
+	},
+	/// A relative constraint over multiple lengths (`actual == Op(Reduction(relative_to), By)`) was
+	/// violated
+	RelativeMulti {
+		/// The expected length computed from `relative_to`
+		expected: usize,
+		/// The actual length
+		actual: usize,
+		/// All lengths the expected length was computed from
+		relative_to: RelativeLens,
+		/// The formula of the violated constraint
+		formula: Formula
+	},
+	/// A relative lower bound constraint (`actual >= Op(relative_to, By)`) was violated
+	RelativeAtLeast {
+		/// The inclusive lower bound computed from `relative_to`
+		min: usize,
+		/// The actual length
+		actual: usize,
+		/// The length the bound was computed from
+		relative_to: usize,
+		/// The formula of the violated constraint
+		formula: Formula
+	},
+	/// A relative upper bound constraint (`actual <= Op(relative_to, By)`) was violated
+	RelativeAtMost {
+		/// The inclusive upper bound computed from `relative_to`
+		max: usize,
+		/// The actual length
+		actual: usize,
+		/// The length the bound was computed from
+		relative_to: usize,
+		/// The formula of the violated constraint
+		formula: Formula
+	},
+	/// A relative tolerance constraint (`min <= actual <= max` where the bounds are computed from
+	/// `Op(relative_to, By)`) was violated
+	RelativeWithin {
+		/// The inclusive lower bound computed from `relative_to`
+		min: usize,
+		/// The inclusive upper bound computed from `relative_to`
+		max: usize,
+		/// The actual length
+		actual: usize,
+		/// The length the bounds were computed from
+		relative_to: usize,
+		/// The formula of the violated constraint
+		formula: Formula
+	},
+	/// An arithmetic operation overflowed
+	Overflow,
+	/// An arithmetic operation underflowed
+	Underflow,
+	/// An arithmetic operation attempted to divide by zero
+	DivisionByZero
 }
 impl ConstraintViolation {
-	/// Creates a new error in case a fixed constraint was violated
-	pub fn fixed<Val: TypeNum>(len: usize) -> Self {
-		// Compute diff
-		let by = i128::try_from(len).unwrap() - i128::try_from(Val::VALUE).unwrap();
-		assert_ne!(by, 0, "Cannot construct `ConstraintViolation` for valid constraint");
-		
-		Self{ constraint: format!("{:?}", Val::default()), by }
-	}
-	/// Creates a new error in case a range constraint was violated
-	pub fn ranged<Start: TypeNum, End: TypeNum>(len: usize) -> Self {
-		// Prepare values
-		let len = i128::try_from(len).unwrap();
-		let (start, end) =
-			(i128::try_from(Start::VALUE).unwrap(), i128::try_from(End::VALUE).unwrap());
-		
-		// Compute diff
-		let by = match (start, end) {
-			(start, _) if len < start => len - start,
-			(_, end) if len >= end => len - (end - 1),
-			_ => panic!("Cannot construct `ConstraintViolation` for valid constraint")
-		};
-		
-		Self{ constraint: format!("Range<{:?} .. {:?}>", Start::default(), End::default()), by }
+	/// Creates a new error if `len` violates the fixed constraint `Val` or returns `None` otherwise
+	pub fn fixed<Val: TypeNum>(len: usize) -> Option<Self> {
+		match len == Val::VALUE {
+			true => None,
+			false => Some(ConstraintViolation::Fixed{ expected: Val::VALUE, actual: len })
+		}
+	}
+	/// Creates a new error if `len` violates the range constraint `Start..End` or returns `None`
+	/// otherwise
+	pub fn ranged<Start: TypeNum, End: TypeNum>(len: usize) -> Option<Self> {
+		match len >= Start::VALUE && len < End::VALUE {
+			true => None,
+			false => Some(ConstraintViolation::Ranged {
+				start: Start::VALUE, end: End::VALUE, actual: len
+			})
+		}
+	}
+	/// Creates a new error if `len` violates the inclusive range constraint `Start..=End` or
+	/// returns `None` otherwise
+	pub fn ranged_inclusive<Start: TypeNum, End: TypeNum>(len: usize) -> Option<Self> {
+		match len >= Start::VALUE && len <= End::VALUE {
+			true => None,
+			false => Some(ConstraintViolation::RangedInclusive {
+				start: Start::VALUE, end: End::VALUE, actual: len
+			})
+		}
+	}
+	/// Creates a new error if `len` violates the lower bound `Min` or returns `None` otherwise
+	pub fn at_least<Min: TypeNum>(len: usize) -> Option<Self> {
+		match len >= Min::VALUE {
+			true => None,
+			false => Some(ConstraintViolation::AtLeast{ min: Min::VALUE, actual: len })
+		}
+	}
+	/// Creates a new error if `len` violates the upper bound `Max` or returns `None` otherwise
+	pub fn at_most<Max: TypeNum>(len: usize) -> Option<Self> {
+		match len <= Max::VALUE {
+			true => None,
+			false => Some(ConstraintViolation::AtMost{ max: Max::VALUE, actual: len })
+		}
 	}
-	/// Creates a new error in case a relative constraint was violated
-	pub fn relative<Op: Operator, By: TypeNum>(len: usize, other: usize) -> Self {
+	/// Creates a new error if `len` violates the relative constraint `Op(other, By)` or returns
+	/// `None` otherwise
+	///
+	/// _Note: If the expected length cannot be computed, the arithmetic error is returned instead_
+	pub fn relative<Op: Operator, By: TypeNum>(len: usize, other: usize) -> Option<Self> {
 		// Compute the absolute length of the relative constraint
-		let abs_len = Op::r#do(other, By::VALUE)
-			.expect("Cannot construct `ConstraintViolation` for illegal constraint");
+		let expected = match Op::r#do(other, By::VALUE) {
+			Ok(expected) => expected,
+			Err(e) => return Some(e)
+		};
 		
-		// Compute diff
-		let by = i128::try_from(len).unwrap() - i128::try_from(abs_len).unwrap();
-		assert_ne!(by, 0, "Cannot construct `ConstraintViolation` for valid constraint");
+		match len == expected {
+			true => None,
+			false => Some(ConstraintViolation::Relative {
+				expected, actual: len, relative_to: other,
+				formula: Formula::of::<Relative<Op, By>>()
+			})
+		}
+	}
+	/// Creates a new error if `len` violates the relative constraint `Op(R(others), By)` or returns
+	/// `None` otherwise
+	///
+	/// _Note: If the expected length cannot be computed, the arithmetic error is returned instead_
+	pub fn relative_multi<R: Reduction, Op: Operator, By: TypeNum, const N: usize>(len: usize,
+		others: [usize; N]) -> Option<Self>
+	{
+		let relative_to = RelativeLens::new(others);
+		let expected = match R::reduce(&others).and_then(|other| Op::r#do(other, By::VALUE)) {
+			Ok(expected) => expected,
+			Err(e) => return Some(e)
+		};
 		
-		Self{ constraint: format!("{:?}({:?})", Op::default(), By::default()), by }
+		match len == expected {
+			true => None,
+			false => Some(ConstraintViolation::RelativeMulti {
+				expected, actual: len, relative_to,
+				formula: Formula::of::<RelativeMulti<R, Op, By>>()
+			})
+		}
+	}
+	/// Creates a new error if `len` violates the relative constraint `len >= Op(other, By)` or
+	/// returns `None` otherwise
+	///
+	/// _Note: If the minimum length cannot be computed, the arithmetic error is returned instead_
+	pub fn relative_at_least<Op: Operator, By: TypeNum>(len: usize, other: usize) -> Option<Self> {
+		match Op::r#do(other, By::VALUE) {
+			Ok(min) if len < min => Some(ConstraintViolation::RelativeAtLeast {
+				min, actual: len, relative_to: other,
+				formula: Formula::of::<RelativeAtLeast<Op, By>>()
+			}),
+			Ok(_) => None,
+			Err(e) => Some(e)
+		}
+	}
+	/// Creates a new error if `len` violates the relative constraint `len <= Op(other, By)` or
+	/// returns `None` otherwise
+	///
+	/// _Note: If the maximum length cannot be computed, the arithmetic error is returned instead_
+	pub fn relative_at_most<Op: Operator, By: TypeNum>(len: usize, other: usize) -> Option<Self> {
+		match Op::r#do(other, By::VALUE) {
+			Ok(max) if len > max => Some(ConstraintViolation::RelativeAtMost {
+				max, actual: len, relative_to: other,
+				formula: Formula::of::<RelativeAtMost<Op, By>>()
+			}),
+			Ok(_) => None,
+			Err(e) => Some(e)
+		}
+	}
+	/// Creates a new error if `len` differs by more than `Tolerance` from `Op(other, By)` or
+	/// returns `None` otherwise
+	///
+	/// _Note: If the expected length cannot be computed, the arithmetic error is returned instead;
+	/// the tolerance window is clamped to `0..=usize::MAX`_
+	pub fn relative_within<Op: Operator, By: TypeNum, Tolerance: TypeNum>(len: usize, other: usize)
+		-> Option<Self>
+	{
+		match Op::r#do(other, By::VALUE) {
+			Ok(expected) => {
+				let (min, max) = (
+					expected.saturating_sub(Tolerance::VALUE),
+					expected.saturating_add(Tolerance::VALUE)
+				);
+				match len >= min && len <= max {
+					true => None,
+					false => Some(ConstraintViolation::RelativeWithin {
+						min, max, actual: len, relative_to: other,
+						formula: Formula::of::<RelativeWithin<Op, By, Tolerance>>()
+					})
+				}
+			},
+			Err(e) => Some(e)
+		}
+	}
+	
+	/// The signed distance between the actual length and the nearest valid length or `None` if
+	/// this is an arithmetic error
+	pub fn by(&self) -> Option<i128> {
+		match *self {
+			ConstraintViolation::Fixed{ expected, actual }
+				| ConstraintViolation::Relative{ expected, actual, .. }
+				| ConstraintViolation::RelativeMulti{ expected, actual, .. } =>
+				Some(actual as i128 - expected as i128),
+			ConstraintViolation::Ranged{ start, actual, .. } if actual < start =>
+				Some(actual as i128 - start as i128),
+			ConstraintViolation::Ranged{ end, actual, .. } =>
+				Some(actual as i128 - (end as i128 - 1)),
+			ConstraintViolation::RangedInclusive{ start, actual, .. } if actual < start =>
+				Some(actual as i128 - start as i128),
+			ConstraintViolation::RangedInclusive{ end, actual, .. } =>
+				Some(actual as i128 - end as i128),
+			ConstraintViolation::AtLeast{ min, actual } => Some(actual as i128 - min as i128),
+			ConstraintViolation::AtMost{ max, actual } => Some(actual as i128 - max as i128),
+			ConstraintViolation::RelativeAtLeast{ min, actual, .. } =>
+				Some(actual as i128 - min as i128),
+			ConstraintViolation::RelativeAtMost{ max, actual, .. } =>
+				Some(actual as i128 - max as i128),
+			ConstraintViolation::RelativeWithin{ min, actual, .. } if actual < min =>
+				Some(actual as i128 - min as i128),
+			ConstraintViolation::RelativeWithin{ max, actual, .. } =>
+				Some(actual as i128 - max as i128),
+			_ => None
+		}
 	}
 }
 impl Display for ConstraintViolation {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		let sign = match self.by.is_positive() {
-			true => "+",
-			false => ""
-		};
-		write!(f, "The length constraint `{}` was violated by {}{}", self.constraint, sign, self.by)
+		let by = self.by().unwrap_or_default();
+		match *self {
+			ConstraintViolation::Fixed{ expected, .. } => write!(f,
+				"The length constraint `len == {}` was violated by {:+}", expected, by),
+			ConstraintViolation::Ranged{ start, end, .. } => write!(f,
+				"The length constraint `{} <= len < {}` was violated by {:+}", start, end, by),
+			ConstraintViolation::RangedInclusive{ start, end, .. } => write!(f,
+				"The length constraint `{} <= len <= {}` was violated by {:+}", start, end, by),
+			ConstraintViolation::AtLeast{ min, .. } => write!(f,
+				"The length constraint `len >= {}` was violated by {:+}", min, by),
+			ConstraintViolation::AtMost{ max, .. } => write!(f,
+				"The length constraint `len <= {}` was violated by {:+}", max, by),
+			ConstraintViolation::Relative{ expected, relative_to, formula, .. } => write!(f,
+				"The length constraint `{}` (`len == {}` relative to {}) was violated by {:+}",
+				formula, expected, relative_to, by),
+			ConstraintViolation::RelativeMulti{ expected, relative_to, formula, .. } => write!(f,
+				"The length constraint `{}` (`len == {}` relative to {}) was violated by {:+}",
+				formula, expected, relative_to, by),
+			ConstraintViolation::RelativeAtLeast{ min, relative_to, formula, .. } => write!(f,
+				"The length constraint `{}` (`len >= {}` relative to {}) was violated by {:+}",
+				formula, min, relative_to, by),
+			ConstraintViolation::RelativeAtMost{ max, relative_to, formula, .. } => write!(f,
+				"The length constraint `{}` (`len <= {}` relative to {}) was violated by {:+}",
+				formula, max, relative_to, by),
+			ConstraintViolation::RelativeWithin{ min, max, relative_to, formula, .. } => write!(f,
+				"The length constraint `{}` (`{} <= len <= {}` relative to {}) was violated by \
+				{:+}", formula, min, max, relative_to, by),
+			ConstraintViolation::Overflow => write!(f, "Integer overflow"),
+			ConstraintViolation::Underflow => write!(f, "Integer underflow"),
+			ConstraintViolation::DivisionByZero => write!(f, "Division by zero")
+		}
 	}
 }
+#[cfg(feature = "std")]
 impl Error for ConstraintViolation {}
+#[cfg(feature = "std")]
+impl From<ConstraintViolation> for io::Error {
+	fn from(error: ConstraintViolation) -> Self {
+		io::Error::new(io::ErrorKind::InvalidInput, error)
+	}
+}
+
+
+/// The formula of a relative constraint as it appears in a `ConstraintViolation` message (e.g.
+/// `len == relative_to + 16`)
+///
+/// _Note: The formula is rendered from the constraint type on demand so that `ConstraintViolation`
+/// stays `Copy`; two formulas are equal if they render the same text_
+#[derive(Copy, Clone)]
+pub struct Formula(fn(&mut Formatter) -> fmt::Result);
+impl Formula {
+	/// The formula of the relative constraint `C` as written by its `Debug` implementation
+	pub fn of<C: Debug + Default>() -> Self {
+		Self(|f| Debug::fmt(&C::default(), f))
+	}
+	
+	/// The byte at `index` of the rendered formula (if any)
+	fn byte_at(&self, index: usize) -> Option<u8> {
+		/// A sink that stops as soon as it reaches the byte at `index`
+		struct ByteAt {
+			index: usize,
+			byte: Option<u8>
+		}
+		impl fmt::Write for ByteAt {
+			fn write_str(&mut self, s: &str) -> fmt::Result {
+				match s.as_bytes().get(self.index) {
+					Some(byte) => {
+						self.byte = Some(*byte);
+						Err(fmt::Error)
+					},
+					None => {
+						self.index -= s.len();
+						Ok(())
+					}
+				}
+			}
+		}
+		
+		let mut sink = ByteAt{ index, byte: None };
+		let _ = fmt::write(&mut sink, format_args!("{}", self));
+		sink.byte
+	}
+}
+impl Debug for Formula {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		(self.0)(f)
+	}
+}
+impl Display for Formula {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		(self.0)(f)
+	}
+}
+impl PartialEq for Formula {
+	/// Compares the rendered formulas byte by byte without allocating
+	fn eq(&self, other: &Self) -> bool {
+		/// A sink that compares everything written to it against the rendered formula `this`
+		struct Compare<'a> {
+			this: &'a Formula,
+			offset: usize
+		}
+		impl fmt::Write for Compare<'_> {
+			fn write_str(&mut self, s: &str) -> fmt::Result {
+				for byte in s.bytes() {
+					if self.this.byte_at(self.offset) != Some(byte) {
+						return Err(fmt::Error);
+					}
+					self.offset += 1;
+				}
+				Ok(())
+			}
+		}
+		
+		let mut sink = Compare{ this: self, offset: 0 };
+		let equal_prefix = fmt::write(&mut sink, format_args!("{}", other)).is_ok();
+		equal_prefix && self.byte_at(sink.offset).is_none()
+	}
+}
+impl Eq for Formula {}
 
 
+/// The lengths a multi-operand relative constraint was computed from
+///
+/// _Note: The lengths are stored inline so that `ConstraintViolation` stays `Copy`; to keep every
+/// `Result<_, ConstraintViolation>` small, only the first `CAPACITY` lengths are stored together
+/// with the total amount of lengths_
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RelativeLens {
+	/// The first lengths (only the first `min(count, CAPACITY)` are valid)
+	lens: [usize; RelativeLens::CAPACITY],
+	/// The total amount of lengths
+	count: usize
+}
+impl RelativeLens {
+	/// The maximum amount of lengths that are stored (e.g. header, nonce and body)
+	pub const CAPACITY: usize = 3;
+	
+	/// Creates a new length list from `lens`
+	///
+	/// _Note: If `N` exceeds `Self::CAPACITY`, only the first `Self::CAPACITY` lengths are stored_
+	pub fn new<const N: usize>(lens: [usize; N]) -> Self {
+		let stored = N.min(Self::CAPACITY);
+		let mut this = Self{ lens: [0; Self::CAPACITY], count: N };
+		this.lens[.. stored].copy_from_slice(&lens[.. stored]);
+		this
+	}
+	/// The stored lengths as slice
+	///
+	/// _Note: The slice contains at most `Self::CAPACITY` lengths; use `count` to get the total
+	/// amount of lengths_
+	pub fn as_slice(&self) -> &[usize] {
+		&self.lens[.. self.count.min(Self::CAPACITY)]
+	}
+	/// The total amount of lengths (including those that were not stored)
+	pub fn count(&self) -> usize {
+		self.count
+	}
+}
+impl Display for RelativeLens {
+	/// Writes the lengths as list (e.g. `[4, 16, 7, … (5 in total)]` if some were not stored)
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		if self.count <= Self::CAPACITY {
+			return write!(f, "{:?}", self.as_slice());
+		}
+		
+		write!(f, "[")?;
+		for len in self.as_slice() {
+			write!(f, "{}, ", len)?;
+		}
+		write!(f, "… ({} in total)]", self.count)
+	}
+}
@@ -0,0 +1,398 @@
+use crate::{
+	ConstraintViolation, Constrained,
+	constraint::{ self, LenConstraint },
+	slice::{ Fixed, Ranged }, slice_mut::{ FixedMut, RangedMut },
+	type_math::{ TypeNum, ArrayLen, Const, _0 }
+};
+use core::{
+	mem, convert::TryFrom, marker::PhantomData, slice::SliceIndex,
+	fmt::{ self, Debug, Formatter },
+	ops::{ Deref, DerefMut, Index, IndexMut }
+};
+#[cfg(feature = "alloc")]
+use alloc::{ boxed::Box, vec::Vec };
+#[cfg(feature = "alloc")]
+use core::ops::{ RangeBounds, Bound };
+
+
+/// Implements the conversion traits
+macro_rules! impl_conv {
+	($type:ty where $($impl_args:tt)*) => {
+		impl<$($impl_args)*> $type {
+			/// The length of the constrained container
+			pub fn len(&self) -> usize {
+				self.as_ref().len()
+			}
+			/// Whether the constrained container is empty or not
+			pub fn is_empty(&self) -> bool {
+				self.as_ref().is_empty()
+			}
+		}
+		impl<$($impl_args)*> Deref for $type {
+			type Target = [T];
+			fn deref(&self) -> &Self::Target {
+				self.as_ref()
+			}
+		}
+		impl<$($impl_args)*> DerefMut for $type {
+			fn deref_mut(&mut self) -> &mut Self::Target {
+				self.as_mut()
+			}
+		}
+		impl<$($impl_args)*, I: SliceIndex<[T]>> Index<I> for $type {
+			type Output = I::Output;
+			fn index(&self, index: I) -> &Self::Output {
+				&self.as_ref()[index]
+			}
+		}
+		impl<$($impl_args)*, I: SliceIndex<[T]>> IndexMut<I> for $type {
+			fn index_mut(&mut self, index: I) -> &mut Self::Output {
+				&mut self.as_mut()[index]
+			}
+		}
+	};
+}
+/// Implements the lending functions for fixed containers
+macro_rules! impl_fixed {
+	($type:ty where $($impl_args:tt)*) => {
+		impl<$($impl_args)*> $type {
+			/// Lends the contents out as constrained slice
+			pub fn as_fixed(&self) -> Fixed<'_, T, Val> {
+				Constrained{ inner: self.as_ref(), constraint: PhantomData }
+			}
+			/// Lends the contents out as mutable constrained slice
+			pub fn as_fixed_mut(&mut self) -> FixedMut<'_, T, Val> {
+				Constrained{ inner: self.as_mut(), constraint: PhantomData }
+			}
+		}
+	};
+}
+
+
+/// An owned inline array with a fixed length as length constraint
+#[derive(Debug)]
+pub struct FixedArray<T, Val: ArrayLen<T>> {
+	array: Val::Array
+}
+impl<T, Val: ArrayLen<T>> FixedArray<T, Val> {
+	/// Creates the constrained array from `array`
+	pub fn new(array: Val::Array) -> Self {
+		Self{ array }
+	}
+	/// Returns the underlying array
+	pub fn into_inner(self) -> Val::Array {
+		self.array
+	}
+}
+impl<T, Val: ArrayLen<T>> Clone for FixedArray<T, Val> where Val::Array: Clone {
+	fn clone(&self) -> Self {
+		Self{ array: self.array.clone() }
+	}
+}
+impl<'a, T: Clone, Val: ArrayLen<T>> TryFrom<&'a[T]> for FixedArray<T, Val> {
+	type Error = ConstraintViolation;
+	/// Validates `slice` against the length constraint and copies it into a new constrained array
+	fn try_from(slice: &'a[T]) -> Result<Self, Self::Error> {
+		constraint::Fixed::<Val>::check(slice.len())?;
+		Ok(Self{ array: Val::array_from_fn(|i| slice[i].clone()) })
+	}
+}
+impl<'a, T: Clone, Val: ArrayLen<T>> From<Fixed<'a, T, Val>> for FixedArray<T, Val> {
+	fn from(fixed: Fixed<'a, T, Val>) -> Self {
+		Self{ array: Val::array_from_fn(|i| fixed[i].clone()) }
+	}
+}
+impl<T, Val: ArrayLen<T>> AsRef<[T]> for FixedArray<T, Val> {
+	fn as_ref(&self) -> &[T] {
+		self.array.as_ref()
+	}
+}
+impl<T, Val: ArrayLen<T>> AsMut<[T]> for FixedArray<T, Val> {
+	fn as_mut(&mut self) -> &mut[T] {
+		self.array.as_mut()
+	}
+}
+impl_conv!(FixedArray<T, Val> where T, Val: ArrayLen<T>);
+impl_fixed!(FixedArray<T, Val> where T, Val: ArrayLen<T>);
+
+
+/// An owned inline array with a range as length constraint that can grow up to `End::VALUE - 1`
+/// elements without any heap allocation
+///
+/// _Note: Unused slots are filled with `T::default()`, so creating and mutating the array requires
+/// `T: Default` (lending it out as constrained slice does not); like `RangedVec`, all mutating
+/// functions validate the resulting length before they modify the array_
+///
+/// _Note: An empty array can be created with `new`/`Default` if `Start` is `_0` or `Const<0>`_
+pub struct RangedArray<T, Start: TypeNum, End: ArrayLen<T>> {
+	array: End::Array,
+	len: usize,
+	constraint: PhantomData<Start>
+}
+impl<T, Start: TypeNum, End: ArrayLen<T>> RangedArray<T, Start, End> {
+	/// Lends the contents out as constrained slice
+	pub fn as_ranged(&self) -> Ranged<'_, T, Start, End> {
+		Constrained{ inner: self.as_ref(), constraint: PhantomData }
+	}
+	/// Lends the contents out as mutable constrained slice
+	pub fn as_ranged_mut(&mut self) -> RangedMut<'_, T, Start, End> {
+		Constrained{ inner: self.as_mut(), constraint: PhantomData }
+	}
+}
+impl<T: Default, Start: TypeNum, End: ArrayLen<T>> RangedArray<T, Start, End> {
+	/// Appends `value` to the back of the array
+	pub fn push(&mut self, value: T) -> Result<(), ConstraintViolation> {
+		Self::validate(self.len + 1)?;
+		self.array.as_mut()[self.len] = value;
+		self.len += 1;
+		Ok(())
+	}
+	/// Inserts `value` at position `index`
+	///
+	/// _Note: Like `Vec::insert`, this function panics if `index > len`_
+	pub fn insert(&mut self, index: usize, value: T) -> Result<(), ConstraintViolation> {
+		assert!(index <= self.len, "Insertion index is out of bounds");
+		self.push(value)?;
+		self.array.as_mut()[index .. self.len].rotate_right(1);
+		Ok(())
+	}
+	/// Removes the last element and returns it or `None` if the array is empty
+	pub fn pop(&mut self) -> Result<Option<T>, ConstraintViolation> {
+		let len = match self.len.checked_sub(1) {
+			Some(len) => len,
+			None => return Ok(None)
+		};
+		
+		Self::validate(len)?;
+		self.len = len;
+		Ok(Some(mem::take(&mut self.array.as_mut()[len])))
+	}
+	/// Shortens the array to `len` (does nothing if `len` is greater than the current length)
+	pub fn truncate(&mut self, len: usize) -> Result<(), ConstraintViolation> {
+		if len < self.len {
+			Self::validate(len)?;
+			self.array.as_mut()[len .. self.len].iter_mut().for_each(|slot| *slot = T::default());
+			self.len = len;
+		}
+		Ok(())
+	}
+	
+	/// Validates that `len` is within the constraint range
+	fn validate(len: usize) -> Result<(), ConstraintViolation> {
+		constraint::Ranged::<Start, End>::check(len)
+	}
+}
+/// Implements `new` and `Default` for the empty array if `Start` is the zero type number `$zero`
+macro_rules! impl_empty {
+	($zero:ty) => {
+		impl<T: Default, End: ArrayLen<T>> RangedArray<T, $zero, End> {
+			/// Creates a new empty array
+			pub fn new() -> Self {
+				let () = constraint::Ranged::<$zero, End>::ASSERT;
+				Self{ array: End::array_from_fn(|_| T::default()), len: 0, constraint: PhantomData }
+			}
+		}
+		impl<T: Default, End: ArrayLen<T>> Default for RangedArray<T, $zero, End> {
+			fn default() -> Self {
+				Self::new()
+			}
+		}
+	};
+}
+impl_empty!(_0);
+impl_empty!(Const<0>);
+impl<T: Default + Clone, Start: TypeNum, End: ArrayLen<T>> RangedArray<T, Start, End> {
+	/// Clones and appends all elements in `slice` to the array
+	pub fn extend_from_slice(&mut self, slice: &[T]) -> Result<(), ConstraintViolation> {
+		let len = self.len.saturating_add(slice.len());
+		Self::validate(len)?;
+		self.array.as_mut()[self.len .. len].clone_from_slice(slice);
+		self.len = len;
+		Ok(())
+	}
+}
+impl<T: Debug, Start: TypeNum, End: ArrayLen<T>> Debug for RangedArray<T, Start, End> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_struct("RangedArray")
+			.field("array", &self.as_ref())
+			.field("constraint", &PhantomData::<(Start, End)>)
+			.finish()
+	}
+}
+impl<T, Start: TypeNum, End: ArrayLen<T>> Clone for RangedArray<T, Start, End>
+	where End::Array: Clone
+{
+	fn clone(&self) -> Self {
+		Self{ array: self.array.clone(), len: self.len, constraint: PhantomData }
+	}
+}
+impl<'a, T: Default + Clone, Start: TypeNum, End: ArrayLen<T>> TryFrom<&'a[T]>
+	for RangedArray<T, Start, End>
+{
+	type Error = ConstraintViolation;
+	/// Validates `slice` against the length constraint and copies it into a new constrained array
+	fn try_from(slice: &'a[T]) -> Result<Self, Self::Error> {
+		Self::validate(slice.len())?;
+		let array = End::array_from_fn(|i| slice.get(i).cloned().unwrap_or_default());
+		Ok(Self{ array, len: slice.len(), constraint: PhantomData })
+	}
+}
+impl<'a, T: Default + Clone, Start: TypeNum, End: ArrayLen<T>> From<Ranged<'a, T, Start, End>>
+	for RangedArray<T, Start, End>
+{
+	fn from(ranged: Ranged<'a, T, Start, End>) -> Self {
+		let array = End::array_from_fn(|i| ranged.inner.get(i).cloned().unwrap_or_default());
+		Self{ array, len: ranged.inner.len(), constraint: PhantomData }
+	}
+}
+impl<T, Start: TypeNum, End: ArrayLen<T>> AsRef<[T]> for RangedArray<T, Start, End> {
+	fn as_ref(&self) -> &[T] {
+		&self.array.as_ref()[.. self.len]
+	}
+}
+impl<T, Start: TypeNum, End: ArrayLen<T>> AsMut<[T]> for RangedArray<T, Start, End> {
+	fn as_mut(&mut self) -> &mut[T] {
+		&mut self.array.as_mut()[.. self.len]
+	}
+}
+impl_conv!(RangedArray<T, Start, End> where T, Start: TypeNum, End: ArrayLen<T>);
+
+
+/// An owned inline array with the const generic `N` as fixed length
+pub type FixedArrayN<T, const N: usize> = FixedArray<T, Const<N>>;
+
+/// An owned inline array with the const generic range `START <= len < END` as length constraint
+pub type RangedArrayN<T, const START: usize, const END: usize> =
+	RangedArray<T, Const<START>, Const<END>>;
+
+
+/// An owned boxed slice with a fixed length as length constraint
+#[cfg(feature = "alloc")]
+pub type FixedBox<T, Val> = Constrained<Box<[T]>, constraint::Fixed<Val>>;
+
+/// An owned vector with a fixed length as length constraint
+///
+/// _Note: To preserve the constraint, the vector can only be accessed as slice; use `Vec::from` to
+/// get the underlying vector back_
+#[cfg(feature = "alloc")]
+pub type FixedVec<T, Val> = Constrained<Vec<T>, constraint::Fixed<Val>>;
+
+/// An owned growable vector with a range as length constraint
+///
+/// _Note: All mutating functions validate the resulting length before they modify the vector, so
+/// the constraint can never be broken_
+#[cfg(feature = "alloc")]
+pub type RangedVec<T, Start, End> = Constrained<Vec<T>, constraint::Ranged<Start, End>>;
+
+/// An owned boxed slice with the const generic `N` as fixed length
+#[cfg(feature = "alloc")]
+pub type FixedBoxN<T, const N: usize> = FixedBox<T, Const<N>>;
+
+/// An owned vector with the const generic `N` as fixed length
+#[cfg(feature = "alloc")]
+pub type FixedVecN<T, const N: usize> = FixedVec<T, Const<N>>;
+
+/// An owned growable vector with the const generic range `START <= len < END` as length constraint
+#[cfg(feature = "alloc")]
+pub type RangedVecN<T, const START: usize, const END: usize> =
+	RangedVec<T, Const<START>, Const<END>>;
+
+
+#[cfg(feature = "alloc")]
+impl<'a, T: Clone, C: LenConstraint> From<Constrained<&'a[T], C>> for Constrained<Box<[T]>, C> {
+	fn from(constrained: Constrained<&'a[T], C>) -> Self {
+		Constrained{ inner: constrained.inner.into(), constraint: PhantomData }
+	}
+}
+#[cfg(feature = "alloc")]
+impl<T, C: LenConstraint> From<Constrained<Vec<T>, C>> for Constrained<Box<[T]>, C> {
+	fn from(constrained: Constrained<Vec<T>, C>) -> Self {
+		Constrained{ inner: constrained.inner.into_boxed_slice(), constraint: PhantomData }
+	}
+}
+
+
+#[cfg(feature = "alloc")]
+impl<T, C: LenConstraint> Constrained<Vec<T>, C> {
+	/// The amount of elements the vector can hold without reallocating
+	pub fn capacity(&self) -> usize {
+		self.inner.capacity()
+	}
+	
+	/// Appends `value` to the back of the vector
+	pub fn push(&mut self, value: T) -> Result<(), ConstraintViolation> {
+		C::check(self.inner.len() + 1)?;
+		self.inner.push(value);
+		Ok(())
+	}
+	/// Inserts `value` at position `index`
+	///
+	/// _Note: Like `Vec::insert`, this function panics if `index > len`_
+	pub fn insert(&mut self, index: usize, value: T) -> Result<(), ConstraintViolation> {
+		C::check(self.inner.len() + 1)?;
+		self.inner.insert(index, value);
+		Ok(())
+	}
+	/// Removes the last element and returns it or `None` if the vector is empty
+	pub fn pop(&mut self) -> Result<Option<T>, ConstraintViolation> {
+		if let Some(len) = self.inner.len().checked_sub(1) {
+			C::check(len)?;
+		}
+		Ok(self.inner.pop())
+	}
+	/// Shortens the vector to `len` (does nothing if `len` is greater than the current length)
+	pub fn truncate(&mut self, len: usize) -> Result<(), ConstraintViolation> {
+		if len < self.inner.len() {
+			C::check(len)?;
+		}
+		self.inner.truncate(len);
+		Ok(())
+	}
+	/// Removes the elements in `range` and returns them
+	///
+	/// _Note: Like `Vec::drain`, this function panics if `range` is out of bounds; unlike
+	/// `Vec::drain`, the elements are removed eagerly so that the vector can never be left with an
+	/// invalid length (e.g. by leaking the iterator)_
+	pub fn drain<R: RangeBounds<usize>>(&mut self, range: R)
+		-> Result<Vec<T>, ConstraintViolation>
+	{
+		// Compute the amount of removed elements
+		let start = match range.start_bound() {
+			Bound::Included(start) => *start,
+			Bound::Excluded(start) => start.saturating_add(1),
+			Bound::Unbounded => 0
+		};
+		let end = match range.end_bound() {
+			Bound::Included(end) => end.saturating_add(1),
+			Bound::Excluded(end) => *end,
+			Bound::Unbounded => self.inner.len()
+		};
+		let removed = end.saturating_sub(start).min(self.inner.len());
+		
+		C::check(self.inner.len() - removed)?;
+		Ok(self.inner.drain(range).collect())
+	}
+}
+#[cfg(feature = "alloc")]
+impl<T: Clone, C: LenConstraint> Constrained<Vec<T>, C> {
+	/// Clones and appends all elements in `slice` to the vector
+	pub fn extend_from_slice(&mut self, slice: &[T]) -> Result<(), ConstraintViolation> {
+		C::check(self.inner.len().saturating_add(slice.len()))?;
+		self.inner.extend_from_slice(slice);
+		Ok(())
+	}
+}
+#[cfg(feature = "alloc")]
+impl<'a, T: Clone, C: LenConstraint> From<Constrained<&'a[T], C>> for Constrained<Vec<T>, C> {
+	fn from(constrained: Constrained<&'a[T], C>) -> Self {
+		Self::new(constrained.inner.to_vec())
+			.unwrap_or_else(|_| unreachable!("the length was validated on construction"))
+	}
+}
+#[cfg(feature = "alloc")]
+impl<T, C: LenConstraint> From<Constrained<Box<[T]>, C>> for Constrained<Vec<T>, C> {
+	fn from(constrained: Constrained<Box<[T]>, C>) -> Self {
+		Self::new(constrained.inner.into_vec())
+			.unwrap_or_else(|_| unreachable!("the length was validated on construction"))
+	}
+}
@@ -0,0 +1,209 @@
+use crate::{ ConstraintViolation, type_math::{ Add, _0 } };
+use core::{ mem, iter::Zip, ops::{ Deref, DerefMut } };
+
+
+/// Prevents `SliceRef` from being implemented outside of this crate
+mod private {
+	pub trait Sealed {}
+}
+/// A slice reference (`&[T]` or `&mut[T]`) whose length cannot change behind our back
+///
+/// _Note: `SameLen` relies on stable lengths to skip bounds checks, so this trait is sealed_
+pub trait SliceRef: Deref<Target = [Self::Elem]> + private::Sealed {
+	/// The slice element type
+	type Elem;
+}
+impl<T> private::Sealed for &[T] {}
+impl<T> SliceRef for &[T] {
+	type Elem = T;
+}
+impl<T> private::Sealed for &mut[T] {}
+impl<T> SliceRef for &mut[T] {
+	type Elem = T;
+}
+
+
+/// Multiple slice references `P` (`&[T]` or `&mut[T]`) that are validated once to have the same
+/// length
+///
+/// _Note: Every slice is validated like `Relative<Add, _0>` against the length of the first slice,
+/// so the element access and the lock-step iterators need no further bounds checks_
+#[derive(Debug, Clone)]
+pub struct SameLen<P, const N: usize> {
+	pub(crate) slices: [P; N],
+	pub(crate) len: usize
+}
+impl<P: SliceRef, const N: usize> SameLen<P, N> {
+	/// Validates that all `slices` have the same length and creates the container with them
+	pub fn new(slices: [P; N]) -> Result<Self, ConstraintViolation> {
+		Self::try_from_with(slices)
+	}
+	/// Like `new` but converts a constraint violation into the caller-chosen error `E`
+	pub fn try_from_with<E: From<ConstraintViolation>>(slices: [P; N]) -> Result<Self, E> {
+		let len = slices.first().map_or(0, |slice| slice.len());
+		for slice in slices.iter() {
+			ConstraintViolation::relative::<Add, _0>(slice.len(), len).map_or(Ok(()), Err)?;
+		}
+		Ok(Self{ slices, len })
+	}
+	
+	/// The common length of all slices
+	pub fn len(&self) -> usize {
+		self.len
+	}
+	/// Whether the slices are empty or not
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+	/// The slices
+	pub fn slices(&self) -> &[P; N] {
+		&self.slices
+	}
+	/// Returns the underlying slice pointers
+	pub fn into_inner(self) -> [P; N] {
+		self.slices
+	}
+	
+	/// The elements at `index` of all slices or `None` if `index` is out of bounds
+	pub fn get(&self, index: usize) -> Option<[&P::Elem; N]> {
+		match index < self.len {
+			// SAFETY: All slices have the same length, so `index` is in bounds for every slice
+			true => Some(self.slices.each_ref().map(|slice| unsafe { slice.get_unchecked(index) })),
+			false => None
+		}
+	}
+	/// A lock-step iterator over the elements of all slices
+	pub fn iter(&self) -> Iter<'_, P::Elem, N> {
+		Iter{ slices: self.slices.each_ref().map(|slice| &**slice), len: self.len }
+	}
+}
+impl<P: SliceRef + DerefMut, const N: usize> SameLen<P, N> {
+	/// The mutable elements at `index` of all slices or `None` if `index` is out of bounds
+	pub fn get_mut(&mut self, index: usize) -> Option<[&mut P::Elem; N]> {
+		match index < self.len {
+			// SAFETY: All slices have the same length, so `index` is in bounds for every slice
+			true => Some(self.slices.each_mut().map(|slice| unsafe {
+				slice.get_unchecked_mut(index)
+			})),
+			false => None
+		}
+	}
+	/// A lock-step iterator over the mutable elements of all slices
+	pub fn iter_mut(&mut self) -> IterMut<'_, P::Elem, N> {
+		IterMut{ slices: self.slices.each_mut().map(|slice| &mut **slice), len: self.len }
+	}
+}
+
+
+/// Immutable input slices and mutable output slices that are validated once to have the same
+/// length (e.g. `out[i] = data[i] ^ key[i]`)
+///
+/// _Note: Like `SameLen`, every slice is validated like `Relative<Add, _0>` against the length of
+/// the first slice_
+#[derive(Debug)]
+pub struct SameLenInOut<'a, I, O, const N: usize, const M: usize> {
+	inputs: [&'a[I]; N],
+	outputs: [&'a mut[O]; M],
+	len: usize
+}
+impl<'a, I, O, const N: usize, const M: usize> SameLenInOut<'a, I, O, N, M> {
+	/// Validates that all `inputs` and `outputs` have the same length and creates the container
+	/// with them
+	pub fn new(inputs: [&'a[I]; N], outputs: [&'a mut[O]; M]) -> Result<Self, ConstraintViolation> {
+		Self::try_from_with(inputs, outputs)
+	}
+	/// Like `new` but converts a constraint violation into the caller-chosen error `E`
+	pub fn try_from_with<E: From<ConstraintViolation>>(inputs: [&'a[I]; N],
+		outputs: [&'a mut[O]; M]) -> Result<Self, E>
+	{
+		let lens = inputs.iter().map(|input| input.len())
+			.chain(outputs.iter().map(|output| output.len()));
+		let len = lens.clone().next().unwrap_or(0);
+		for other in lens {
+			ConstraintViolation::relative::<Add, _0>(other, len).map_or(Ok(()), Err)?;
+		}
+		Ok(Self{ inputs, outputs, len })
+	}
+	
+	/// The common length of all slices
+	pub fn len(&self) -> usize {
+		self.len
+	}
+	/// Whether the slices are empty or not
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+	/// Returns the underlying input and output slices
+	pub fn into_inner(self) -> ([&'a[I]; N], [&'a mut[O]; M]) {
+		(self.inputs, self.outputs)
+	}
+	
+	/// The input elements and the mutable output elements at `index` or `None` if `index` is out
+	/// of bounds
+	pub fn get_mut(&mut self, index: usize) -> Option<([&I; N], [&mut O; M])> {
+		if index >= self.len { return None }
+		
+		// SAFETY: All slices have the same length, so `index` is in bounds for every slice
+		let inputs = self.inputs.map(|input| unsafe { input.get_unchecked(index) });
+		let outputs = self.outputs.each_mut().map(|output| unsafe {
+			output.get_unchecked_mut(index)
+		});
+		Some((inputs, outputs))
+	}
+	/// A lock-step iterator over the input elements and the mutable output elements
+	pub fn iter_mut(&mut self) -> Zip<Iter<'_, I, N>, IterMut<'_, O, M>> {
+		let inputs = Iter{ slices: self.inputs, len: self.len };
+		let outputs = self.outputs.each_mut().map(|output| &mut **output);
+		let outputs = IterMut{ slices: outputs, len: self.len };
+		inputs.zip(outputs)
+	}
+}
+
+
+/// A lock-step iterator over the elements of multiple slices with the same length
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T, const N: usize> {
+	slices: [&'a[T]; N],
+	len: usize
+}
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+	type Item = [&'a T; N];
+	
+	fn next(&mut self) -> Option<Self::Item> {
+		self.len = self.len.checked_sub(1)?;
+		Some(self.slices.each_mut().map(|slice| {
+			// SAFETY: All slices have the same remaining length, which was not zero
+			let (first, tail) = unsafe { slice.split_first().unwrap_unchecked() };
+			*slice = tail;
+			first
+		}))
+	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.len, Some(self.len))
+	}
+}
+impl<T, const N: usize> ExactSizeIterator for Iter<'_, T, N> {}
+
+/// A lock-step iterator over the mutable elements of multiple slices with the same length
+#[derive(Debug)]
+pub struct IterMut<'a, T, const N: usize> {
+	slices: [&'a mut[T]; N],
+	len: usize
+}
+impl<'a, T, const N: usize> Iterator for IterMut<'a, T, N> {
+	type Item = [&'a mut T; N];
+	
+	fn next(&mut self) -> Option<Self::Item> {
+		self.len = self.len.checked_sub(1)?;
+		Some(self.slices.each_mut().map(|slice| {
+			// SAFETY: All slices have the same remaining length, which was not zero
+			let (first, tail) = unsafe { mem::take(slice).split_first_mut().unwrap_unchecked() };
+			*slice = tail;
+			first
+		}))
+	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.len, Some(self.len))
+	}
+}
+impl<T, const N: usize> ExactSizeIterator for IterMut<'_, T, N> {}
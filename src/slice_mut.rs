@@ -1,156 +1,79 @@
-use crate::{
-	ConstraintViolation,
-	type_math::{ TypeNum, Operator }
-};
-use std::{
-	convert::TryFrom, error::Error, marker::PhantomData, slice::SliceIndex,
-	ops::{ Deref, DerefMut, Index, IndexMut }
-};
-
-
-/// Implements the conversion traits
-macro_rules! impl_conv {
-	($type:ty where $($impl_args:tt)*) => {
-		impl<$($impl_args)*> $type {
-			/// The length of the constrained slice
-			pub fn len(&self) -> usize {
-				self.slice.len()
-			}
-			/// The constrained slice
-			pub fn slice(&self) -> &[T] {
-				self.slice
-			}
-			/// The mutable constrained slice
-			pub fn slice_mut(&mut self) -> &mut[T] {
-				self.slice
-			}
-		}
-		impl<$($impl_args)*> Into<&'a[T]> for $type {
-			fn into(self) -> &'a[T] {
-				self.slice
-			}
-		}
-		impl<$($impl_args)*> Into<&'a mut[T]> for $type {
-			fn into(self) -> &'a mut[T] {
-				self.slice
-			}
-		}
-		impl<$($impl_args)*> Deref for $type {
-			type Target = [T];
-			fn deref(&self) -> &Self::Target {
-				self.slice
-			}
-		}
-		impl<$($impl_args)*> DerefMut for $type {
-			fn deref_mut(&mut self) -> &mut Self::Target {
-				self.slice
-			}
-		}
-		impl<$($impl_args)*> AsRef<[T]> for $type {
-			fn as_ref(&self) -> &[T] {
-				self.slice
-			}
-		}
-		impl<$($impl_args)*> AsMut<[T]> for $type {
-			fn as_mut(&mut self) -> &mut[T] {
-				self.slice
-			}
-		}
-		impl<$($impl_args)*, I: SliceIndex<[T]>> Index<I> for $type {
-			type Output = I::Output;
-			fn index(&self, index: I) -> &Self::Output {
-				&self.slice[index]
-			}
-		}
-		impl<$($impl_args)*, I: SliceIndex<[T]>> IndexMut<I> for $type {
-			fn index_mut(&mut self, index: I) -> &mut Self::Output {
-				&mut self.slice[index]
-			}
-		}
-	};
-}
-
-
-/// An immutable slice with a fixed length as length constraint
-#[derive(Debug)]
-pub struct FixedMut<'a, T, Val: TypeNum> {
-	slice: &'a mut[T],
-	constraint: PhantomData<Val>
-}
-impl<'a, T, Val: TypeNum> TryFrom<&'a mut[T]> for FixedMut<'a, T, Val> {
-	type Error = Box<Error + 'static>;
-	/// Validates `slice` against the length constraint and creates the constrained slice with it
-	fn try_from(slice: &'a mut[T]) -> Result<Self, Self::Error> {
-		match slice.len() {
-			len if len == Val::VALUE => Ok(Self{ slice, constraint: PhantomData }),
-			len => Err(ConstraintViolation::fixed::<Val>(len))?
-		}
-	}
-}
-impl_conv!(FixedMut<'a, T, Val> where 'a, T, Val: TypeNum);
-
-
-/// An immutable slice with a range as length constraint
-#[derive(Debug)]
-pub struct RangedMut<'a, T, Start: TypeNum, End: TypeNum> {
-	slice: &'a mut[T],
-	constraint: PhantomData<(Start, End)>
-}
-impl<'a, T, Start: TypeNum, End: TypeNum> TryFrom<&'a mut[T]> for RangedMut<'a, T, Start, End> {
-	type Error = Box<Error + 'static>;
-	/// Validates `slice` against the length constraint and creates the constrained slice with it
-	fn try_from(slice: &'a mut[T]) -> Result<Self, Self::Error> {
-		match slice.len() {
-			len if len >= Start::VALUE && len < End::VALUE =>
-				Ok(Self{ slice, constraint: PhantomData }),
-			len => Err(ConstraintViolation::ranged::<Start, End>(len))?
-		}
-	}
-}
-impl_conv!(RangedMut<'a, T, Start, End> where 'a, T, Start: TypeNum, End: TypeNum);
+use crate::{ Constrained, constraint, type_math::Const };
 
 
+/// A mutable slice with a fixed length as length constraint
+pub type FixedMut<'a, T, Val> = Constrained<&'a mut[T], constraint::Fixed<Val>>;
+
+/// A mutable slice with a range as length constraint
+pub type RangedMut<'a, T, Start, End> = Constrained<&'a mut[T], constraint::Ranged<Start, End>>;
+
+/// A mutable slice with an inclusive range (`Start <= len <= End`) as length constraint
+pub type RangedInclusiveMut<'a, T, Start, End> =
+	Constrained<&'a mut[T], constraint::RangedInclusive<Start, End>>;
+
+/// A mutable slice with a lower bound (`len >= Min`) as length constraint
+pub type AtLeastMut<'a, T, Min> = Constrained<&'a mut[T], constraint::AtLeast<Min>>;
+
+/// A mutable slice with an upper bound (`len <= Max`) as length constraint
+pub type AtMostMut<'a, T, Max> = Constrained<&'a mut[T], constraint::AtMost<Max>>;
+
 /// A mutable slice with a relative length as length constraint
 ///
 /// _Note: Unlike the other constrained slices, this type does not validate the constraint on
-/// construction but on deconstruction (`self.try_into()`)_
-#[derive(Debug)]
-pub struct RelativeMut<'a, T, Op: Operator, By: TypeNum> {
-	slice: &'a mut[T],
-	constraint: PhantomData<(Op, By)>
-}
-impl<'a, T, Op: Operator, By: TypeNum> RelativeMut<'a, T, Op, By> {
-	/// Validates `slice` against the length constraint and creates the constrained slice with it
-	pub fn try_from(slice: &'a mut[T], relative_to: usize) -> Result<Self, Box<Error + 'static>> {
-		Self::validate(slice.len(), relative_to)?;
-		Ok(Self::from(slice))
-	}
-	
-	/// Computes the expected relative length from `relative_to`, validates the wrapped `slice`
-	/// against in and returns it on success
-	pub fn slice(self, relative_to: usize) -> Result<&'a[T], Box<Error + 'static>> {
-		Self::validate(self.slice.len(), relative_to)?;
-		Ok(self.slice)
-	}
-	/// Computes the expected relative length from `relative_to`, validates the wrapped `slice`
-	/// against in and returns it on success
-	pub fn slice_mut(self, relative_to: usize) -> Result<&'a mut[T], Box<Error + 'static>> {
-		Self::validate(self.slice.len(), relative_to)?;
-		Ok(self.slice)
-	}
-	
-	/// Validates that `len` is valid relative to `relative_to`
-	fn validate(len: usize, relative_to: usize) -> Result<(), Box<Error + 'static>> {
-		let expected = Op::r#do(relative_to, By::VALUE)?;
-		match len == expected {
-			true => Ok(()),
-			false => Err(ConstraintViolation::relative::<Op, By>(len, relative_to))?
-		}
-	}
-}
-impl<'a, T, Op: Operator, By: TypeNum> From<&'a mut[T]> for RelativeMut<'a, T, Op, By> {
-	/// Creates a new relative constrained slice
-	fn from(slice: &'a mut[T]) -> Self {
-		RelativeMut{ slice, constraint: PhantomData }
-	}
-}
\ No newline at end of file
+/// construction but on deconstruction (`self.slice_mut(relative_to)`)_
+pub type RelativeMut<'a, T, Op, By> = Constrained<&'a mut[T], constraint::Relative<Op, By>>;
+
+/// A mutable slice with a minimum relative length (`len >= Op(relative_to, By)`) as length
+/// constraint
+///
+/// _Note: Like `RelativeMut`, this type validates the constraint on deconstruction
+/// (`self.slice_mut(relative_to)`)_
+pub type RelativeAtLeastMut<'a, T, Op, By> =
+	Constrained<&'a mut[T], constraint::RelativeAtLeast<Op, By>>;
+
+/// A mutable slice with a maximum relative length (`len <= Op(relative_to, By)`) as length
+/// constraint
+///
+/// _Note: Like `RelativeMut`, this type validates the constraint on deconstruction
+/// (`self.slice_mut(relative_to)`)_
+pub type RelativeAtMostMut<'a, T, Op, By> =
+	Constrained<&'a mut[T], constraint::RelativeAtMost<Op, By>>;
+
+/// A mutable slice with a relative length and a tolerance
+/// (`|len - Op(relative_to, By)| <= Tolerance`) as length constraint
+///
+/// _Note: Like `RelativeMut`, this type validates the constraint on deconstruction
+/// (`self.slice_mut(relative_to)`)_
+pub type RelativeWithinMut<'a, T, Op, By, Tolerance> =
+	Constrained<&'a mut[T], constraint::RelativeWithin<Op, By, Tolerance>>;
+
+/// A mutable slice with a relative length over multiple lengths
+/// (`len == Op(R(relative_to), By)`) as length constraint
+///
+/// _Note: Like `RelativeMut`, this type validates the constraint on deconstruction
+/// (`self.slice_mut([...])`)_
+pub type RelativeMultiMut<'a, T, R, Op, By> =
+	Constrained<&'a mut[T], constraint::RelativeMulti<R, Op, By>>;
+
+
+/// A mutable slice with the const generic `N` as fixed length
+pub type FixedMutN<'a, T, const N: usize> = FixedMut<'a, T, Const<N>>;
+
+/// A mutable slice with the const generic range `START <= len < END` as length constraint
+pub type RangedMutN<'a, T, const START: usize, const END: usize> =
+	RangedMut<'a, T, Const<START>, Const<END>>;
+
+/// A mutable slice with the const generic inclusive range `START <= len <= END` as length
+/// constraint
+pub type RangedInclusiveMutN<'a, T, const START: usize, const END: usize> =
+	RangedInclusiveMut<'a, T, Const<START>, Const<END>>;
+
+/// A mutable slice with the const generic `MIN` as lower bound
+pub type AtLeastMutN<'a, T, const MIN: usize> = AtLeastMut<'a, T, Const<MIN>>;
+
+/// A mutable slice with the const generic `MAX` as upper bound
+pub type AtMostMutN<'a, T, const MAX: usize> = AtMostMut<'a, T, Const<MAX>>;
+
+/// A mutable slice with a relative length as length constraint where the operand `BY` is a const
+/// generic
+pub type RelativeMutN<'a, T, Op, const BY: usize> = RelativeMut<'a, T, Op, Const<BY>>;
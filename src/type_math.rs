@@ -1,7 +1,16 @@
-use std::{ error::Error, fmt::Debug };
+use crate::ConstraintViolation;
+use core::{
+	convert::TryFrom, marker::PhantomData,
+	fmt::{ self, Debug, Display, Formatter }
+};
 
 
 /// A type representing a number that can be used as a generic type argument
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` is not a type number",
+	label = "expected a type number",
+	note = "use a predefined type number like `_16`, `Const<N>` or define one with `type_num!`"
+)]
 pub trait TypeNum: Debug + Default {
 	/// The value represented by this type
 	const VALUE: usize;
@@ -11,6 +20,14 @@ pub trait TypeNum: Debug + Default {
 		Self::VALUE
 	}
 }
+/// A `TypeNum` that can be used as length of an inline array of `T`
+pub trait ArrayLen<T>: TypeNum {
+	/// The array type `[T; Self::VALUE]`
+	type Array: AsRef<[T]> + AsMut<[T]>;
+	
+	/// Creates the array by calling `f` for each index
+	fn array_from_fn<F: FnMut(usize) -> T>(f: F) -> Self::Array;
+}
 /// Creates a new type number representing `$value` with `$name` as identifier
 #[macro_export]
 macro_rules! type_num {
@@ -21,6 +38,12 @@ macro_rules! type_num {
 		impl $crate::type_math::TypeNum for $name {
 			const VALUE: usize = $value;
 		}
+		impl<T> $crate::type_math::ArrayLen<T> for $name {
+			type Array = [T; $value];
+			fn array_from_fn<F: FnMut(usize) -> T>(f: F) -> Self::Array {
+				::core::array::from_fn(f)
+			}
+		}
 	);
 	($($value:expr => $name:ident),+) => ( $(type_num!($value => $name);)+ )
 }
@@ -35,18 +58,169 @@ type_num! {
 }
 
 
+/// A type number backed by the const generic `N`; use it for lengths without a predefined
+/// `TypeNum` (e.g. `Const<1500>`)
+#[derive(Debug, Default)]
+pub struct Const<const N: usize>;
+impl<const N: usize> TypeNum for Const<N> {
+	const VALUE: usize = N;
+}
+impl<T, const N: usize> ArrayLen<T> for Const<N> {
+	type Array = [T; N];
+	fn array_from_fn<F: FnMut(usize) -> T>(f: F) -> Self::Array {
+		core::array::from_fn(f)
+	}
+}
+/// Names the canonical type number for the `usize` literal or constant expression `$value`
+///
+/// _Note: Unlike a type defined with `type_num!`, `tn!(1500)` expands to `Const<1500>` and thus
+/// names the same type in every crate_
+#[macro_export]
+macro_rules! tn {
+	($value:expr) => ($crate::type_math::Const<{ $value }>);
+}
+
+
+/// A type number representing `A + B`
+///
+/// _Note: An overflow is rejected at compile time. Because `Self::VALUE` is not a const generic,
+/// this type cannot be used as length of an inline array_
+#[derive(Debug, Default)]
+pub struct Sum<A: TypeNum, B: TypeNum>(PhantomData<(A, B)>);
+impl<A: TypeNum, B: TypeNum> TypeNum for Sum<A, B> {
+	const VALUE: usize = match A::VALUE.checked_add(B::VALUE) {
+		Some(value) => value,
+		None => panic!("`Sum<A, B>` overflows `usize`")
+	};
+}
+
+/// A type number representing `A - B`
+///
+/// _Note: An underflow is rejected at compile time. Because `Self::VALUE` is not a const generic,
+/// this type cannot be used as length of an inline array_
+#[derive(Debug, Default)]
+pub struct Diff<A: TypeNum, B: TypeNum>(PhantomData<(A, B)>);
+impl<A: TypeNum, B: TypeNum> TypeNum for Diff<A, B> {
+	const VALUE: usize = match A::VALUE.checked_sub(B::VALUE) {
+		Some(value) => value,
+		None => panic!("`Diff<A, B>` underflows `usize`")
+	};
+}
+
+/// A type number representing `A * B`
+///
+/// _Note: An overflow is rejected at compile time. Because `Self::VALUE` is not a const generic,
+/// this type cannot be used as length of an inline array_
+#[derive(Debug, Default)]
+pub struct Product<A: TypeNum, B: TypeNum>(PhantomData<(A, B)>);
+impl<A: TypeNum, B: TypeNum> TypeNum for Product<A, B> {
+	const VALUE: usize = match A::VALUE.checked_mul(B::VALUE) {
+		Some(value) => value,
+		None => panic!("`Product<A, B>` overflows `usize`")
+	};
+}
+
+
+/// A compile-time assertion `A < B` between two type numbers
+///
+/// _Note: `ASSERT` is evaluated after monomorphization, so a violation is only reported by a full
+/// build (not by `cargo check`)_
+pub(crate) struct AssertLessThan<A, B>(PhantomData<(A, B)>);
+impl<A: TypeNum, B: TypeNum> AssertLessThan<A, B> {
+	/// Fails to compile if `A::VALUE < B::VALUE` does not hold
+	pub const ASSERT: () = assert!(A::VALUE < B::VALUE, "type number is not less than the bound");
+}
+
+/// A compile-time assertion `A <= B` between two type numbers
+///
+/// _Note: `ASSERT` is evaluated after monomorphization, so a violation is only reported by a full
+/// build (not by `cargo check`)_
+pub(crate) struct AssertLessOrEqual<A, B>(PhantomData<(A, B)>);
+impl<A: TypeNum, B: TypeNum> AssertLessOrEqual<A, B> {
+	/// Fails to compile if `A::VALUE <= B::VALUE` does not hold
+	pub const ASSERT: () = assert!(A::VALUE <= B::VALUE, "type number is greater than the bound");
+}
+
+
 /// A type representing an operator that can be used as a generic type argument
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` is not an operator",
+	label = "expected an operator",
+	note = "use a predefined operator like `Add`, `Sub`, `Mul` or `Div`"
+)]
 pub trait Operator: Debug + Default {
+	/// Whether a zero as right-hand operand is invalid for this operator (e.g. for a division)
+	const REJECTS_ZERO: bool = false;
+	
 	/// Performs the operation represented by this type between `a` and `b`
-	fn r#do(a: usize, b: usize) -> Result<usize, Box<Error + 'static>>;
+	fn r#do(a: usize, b: usize) -> Result<usize, ConstraintViolation>;
+	
+	/// Writes the operation as formula with `a` and `b` as operands (e.g. `a + b`)
+	fn fmt_formula(f: &mut Formatter, a: &dyn Display, b: &dyn Display) -> fmt::Result {
+		write!(f, "{:?}({}, {})", Self::default(), a, b)
+	}
+	
+	/// The largest `a` with `Self::r#do(a, b) <= result` or `None` if there is none (floor
+	/// semantics)
+	///
+	/// _Note: The default implementation performs a binary search and assumes that `Self::r#do` is
+	/// monotonically increasing in `a`_
+	fn inverse_floor(result: usize, b: usize) -> Option<usize> {
+		let a = last_match(|a| match Self::r#do(a, b) {
+			Ok(value) => value <= result,
+			Err(e) => e == ConstraintViolation::Underflow
+		})?;
+		Self::r#do(a, b).ok().map(|_| a)
+	}
+	/// The smallest `a` with `Self::r#do(a, b) >= result` or `None` if there is none (ceil
+	/// semantics)
+	///
+	/// _Note: The default implementation performs a binary search and assumes that `Self::r#do` is
+	/// monotonically increasing in `a`_
+	fn inverse_ceil(result: usize, b: usize) -> Option<usize> {
+		let below = last_match(|a| match Self::r#do(a, b) {
+			Ok(value) => value < result,
+			Err(e) => e == ConstraintViolation::Underflow
+		});
+		let a = match below {
+			None => 0,
+			Some(below) => below.checked_add(1)?
+		};
+		Self::r#do(a, b).ok().map(|_| a)
+	}
+}
+/// Finds the largest `a` that matches `pred` where `pred` must match all values up to a certain
+/// point and none after it
+fn last_match<F: Fn(usize) -> bool>(pred: F) -> Option<usize> {
+	if !pred(0) { return None }
+	if pred(usize::MAX) { return Some(usize::MAX) }
+	
+	let (mut low, mut high) = (0, usize::MAX);
+	while high - low > 1 {
+		let mid = low + (high - low) / 2;
+		match pred(mid) {
+			true => low = mid,
+			false => high = mid
+		}
+	}
+	Some(low)
 }
 
 /// An operator representing an addition
 #[derive(Debug, Default)]
 pub struct Add;
 impl Operator for Add {
-	fn r#do(a: usize, b: usize) -> Result<usize, Box<Error + 'static>> {
-		Ok(a.checked_add(b).ok_or("Integer overflow")?)
+	fn r#do(a: usize, b: usize) -> Result<usize, ConstraintViolation> {
+		a.checked_add(b).ok_or(ConstraintViolation::Overflow)
+	}
+	fn fmt_formula(f: &mut Formatter, a: &dyn Display, b: &dyn Display) -> fmt::Result {
+		write!(f, "{} + {}", a, b)
+	}
+	fn inverse_floor(result: usize, b: usize) -> Option<usize> {
+		result.checked_sub(b)
+	}
+	fn inverse_ceil(result: usize, b: usize) -> Option<usize> {
+		Some(result.saturating_sub(b))
 	}
 }
 
@@ -54,8 +228,17 @@ impl Operator for Add {
 #[derive(Debug, Default)]
 pub struct Sub;
 impl Operator for Sub {
-	fn r#do(a: usize, b: usize) -> Result<usize, Box<Error + 'static>> {
-		Ok(a.checked_sub(b).ok_or("Integer underflow")?)
+	fn r#do(a: usize, b: usize) -> Result<usize, ConstraintViolation> {
+		a.checked_sub(b).ok_or(ConstraintViolation::Underflow)
+	}
+	fn fmt_formula(f: &mut Formatter, a: &dyn Display, b: &dyn Display) -> fmt::Result {
+		write!(f, "{} - {}", a, b)
+	}
+	fn inverse_floor(result: usize, b: usize) -> Option<usize> {
+		Some(result.saturating_add(b))
+	}
+	fn inverse_ceil(result: usize, b: usize) -> Option<usize> {
+		result.checked_add(b)
 	}
 }
 
@@ -63,8 +246,25 @@ impl Operator for Sub {
 #[derive(Debug, Default)]
 pub struct Mul;
 impl Operator for Mul {
-	fn r#do(a: usize, b: usize) -> Result<usize, Box<Error + 'static>> {
-		Ok(a.checked_mul(b).ok_or("Integer overflow")?)
+	fn r#do(a: usize, b: usize) -> Result<usize, ConstraintViolation> {
+		a.checked_mul(b).ok_or(ConstraintViolation::Overflow)
+	}
+	fn fmt_formula(f: &mut Formatter, a: &dyn Display, b: &dyn Display) -> fmt::Result {
+		write!(f, "{} * {}", a, b)
+	}
+	fn inverse_floor(result: usize, b: usize) -> Option<usize> {
+		Some(result.checked_div(b).unwrap_or(usize::MAX))
+	}
+	fn inverse_ceil(result: usize, b: usize) -> Option<usize> {
+		match b {
+			0 if result == 0 => Some(0),
+			0 => None,
+			b => {
+				// Round up and ensure that the product does not overflow
+				let a = result.div_ceil(b);
+				a.checked_mul(b).map(|_| a)
+			}
+		}
 	}
 }
 
@@ -72,7 +272,225 @@ impl Operator for Mul {
 #[derive(Debug, Default)]
 pub struct Div;
 impl Operator for Div {
-	fn r#do(a: usize, b: usize) -> Result<usize, Box<Error + 'static>> {
-		Ok(a.checked_div(b).ok_or("Division by zero")?)
+	const REJECTS_ZERO: bool = true;
+	
+	fn r#do(a: usize, b: usize) -> Result<usize, ConstraintViolation> {
+		a.checked_div(b).ok_or(ConstraintViolation::DivisionByZero)
+	}
+	fn fmt_formula(f: &mut Formatter, a: &dyn Display, b: &dyn Display) -> fmt::Result {
+		write!(f, "{} / {}", a, b)
+	}
+	/// _Note: `Div` rounds down, so the largest `a` is `(result + 1) * b - 1`_
+	fn inverse_floor(result: usize, b: usize) -> Option<usize> {
+		match b {
+			0 => None,
+			b => {
+				let end = result.checked_add(1).and_then(|result| result.checked_mul(b));
+				Some(end.map_or(usize::MAX, |end| end - 1))
+			}
+		}
+	}
+	fn inverse_ceil(result: usize, b: usize) -> Option<usize> {
+		match b {
+			0 => None,
+			b => result.checked_mul(b)
+		}
+	}
+}
+
+/// An operator representing a division that rounds up (e.g. the number of blocks needed for `a`
+/// bytes)
+#[derive(Debug, Default)]
+pub struct CeilDiv;
+impl Operator for CeilDiv {
+	const REJECTS_ZERO: bool = true;
+	
+	fn r#do(a: usize, b: usize) -> Result<usize, ConstraintViolation> {
+		match b {
+			0 => Err(ConstraintViolation::DivisionByZero),
+			b => Ok(a.div_ceil(b))
+		}
+	}
+	fn fmt_formula(f: &mut Formatter, a: &dyn Display, b: &dyn Display) -> fmt::Result {
+		write!(f, "ceil({} / {})", a, b)
 	}
-}
\ No newline at end of file
+}
+
+/// An operator representing the rounding of `a` up to the next multiple of `b` (e.g. a padded
+/// length)
+#[derive(Debug, Default)]
+pub struct AlignUp;
+impl Operator for AlignUp {
+	const REJECTS_ZERO: bool = true;
+	
+	fn r#do(a: usize, b: usize) -> Result<usize, ConstraintViolation> {
+		match b {
+			0 => Err(ConstraintViolation::DivisionByZero),
+			b => a.checked_next_multiple_of(b).ok_or(ConstraintViolation::Overflow)
+		}
+	}
+	fn fmt_formula(f: &mut Formatter, a: &dyn Display, b: &dyn Display) -> fmt::Result {
+		write!(f, "align_up({}, {})", a, b)
+	}
+}
+
+/// An operator representing the remainder of a division
+#[derive(Debug, Default)]
+pub struct Mod;
+impl Operator for Mod {
+	const REJECTS_ZERO: bool = true;
+	
+	fn r#do(a: usize, b: usize) -> Result<usize, ConstraintViolation> {
+		a.checked_rem(b).ok_or(ConstraintViolation::DivisionByZero)
+	}
+	fn fmt_formula(f: &mut Formatter, a: &dyn Display, b: &dyn Display) -> fmt::Result {
+		write!(f, "{} % {}", a, b)
+	}
+	/// _Note: The remainder is periodic, so this is the largest `a` in the last period_
+	fn inverse_floor(result: usize, b: usize) -> Option<usize> {
+		match usize::MAX.checked_rem(b)? {
+			last if last <= result => Some(usize::MAX),
+			last => Some(usize::MAX - (last - result))
+		}
+	}
+	fn inverse_ceil(result: usize, b: usize) -> Option<usize> {
+		match b {
+			0 => None,
+			b if result < b => Some(result),
+			_ => None
+		}
+	}
+}
+
+/// An operator representing a left shift (i.e. a multiplication with `2^b`)
+#[derive(Debug, Default)]
+pub struct Shl;
+impl Operator for Shl {
+	fn r#do(a: usize, b: usize) -> Result<usize, ConstraintViolation> {
+		// Reject shifts that exceed the bit width or shift set bits out
+		let shifted = u32::try_from(b).ok().and_then(|b| a.checked_shl(b));
+		match shifted {
+			Some(shifted) if shifted >> b == a => Ok(shifted),
+			_ => Err(ConstraintViolation::Overflow)
+		}
+	}
+	fn fmt_formula(f: &mut Formatter, a: &dyn Display, b: &dyn Display) -> fmt::Result {
+		write!(f, "{} << {}", a, b)
+	}
+}
+
+/// An operator representing a right shift (i.e. a division by `2^b` that rounds down)
+///
+/// _Note: A shift by `usize::BITS` or more shifts out every bit and thus yields `0`_
+#[derive(Debug, Default)]
+pub struct Shr;
+impl Operator for Shr {
+	fn r#do(a: usize, b: usize) -> Result<usize, ConstraintViolation> {
+		// Shifts that exceed the bit width yield zero
+		let shifted = u32::try_from(b).ok().and_then(|b| a.checked_shr(b));
+		Ok(shifted.unwrap_or(0))
+	}
+	fn fmt_formula(f: &mut Formatter, a: &dyn Display, b: &dyn Display) -> fmt::Result {
+		write!(f, "{} >> {}", a, b)
+	}
+}
+
+
+/// An operator that chains two operations (i.e. `Op2(Op1(a, By1), b)`); use nested `Then`s for
+/// longer formulas
+///
+/// _Note: Every step is checked individually; e.g. the base64 length `ceil(n / 3) * 4` is
+/// `Relative<Then<CeilDiv, tn!(3), Mul>, _4>`_
+#[derive(Default)]
+pub struct Then<Op1: Operator, By1: TypeNum, Op2: Operator>(PhantomData<(Op1, By1, Op2)>);
+impl<Op1: Operator, By1: TypeNum, Op2: Operator> Then<Op1, By1, Op2> {
+	/// Fails to compile if `Op1` rejects `By1` as right-hand operand (e.g. a division by zero)
+	///
+	/// ```compile_fail
+	/// # use len_constraints::type_math::{ Operator, Then, Add, Div, _0 };
+	/// Then::<Div, _0, Add>::r#do(7, 1);
+	/// ```
+	pub const ASSERT: () = assert!(
+		!(Op1::REJECTS_ZERO && By1::VALUE == 0),
+		"the operator `Op1` does not accept zero as `By1`"
+	);
+}
+impl<Op1: Operator, By1: TypeNum, Op2: Operator> Operator for Then<Op1, By1, Op2> {
+	const REJECTS_ZERO: bool = Op2::REJECTS_ZERO;
+	
+	fn r#do(a: usize, b: usize) -> Result<usize, ConstraintViolation> {
+		let () = Self::ASSERT;
+		Op2::r#do(Op1::r#do(a, By1::VALUE)?, b)
+	}
+	fn fmt_formula(f: &mut Formatter, a: &dyn Display, b: &dyn Display) -> fmt::Result {
+		Op2::fmt_formula(f, &Nested::<Op1>(a, By1::VALUE, PhantomData), b)
+	}
+	/// _Note: This assumes that both operators are monotonically increasing in `a`_
+	fn inverse_floor(result: usize, b: usize) -> Option<usize> {
+		let () = Self::ASSERT;
+		Op1::inverse_floor(Op2::inverse_floor(result, b)?, By1::VALUE)
+	}
+	/// _Note: This assumes that both operators are monotonically increasing in `a`_
+	fn inverse_ceil(result: usize, b: usize) -> Option<usize> {
+		let () = Self::ASSERT;
+		Op1::inverse_ceil(Op2::inverse_ceil(result, b)?, By1::VALUE)
+	}
+}
+impl<Op1: Operator, By1: TypeNum, Op2: Operator> Debug for Then<Op1, By1, Op2> {
+	/// Writes the formula with `x` as left-hand and `b` as right-hand operand
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Self::fmt_formula(f, &"x", &"b")
+	}
+}
+/// A parenthesized inner operation of a formula
+struct Nested<'a, Op: Operator>(&'a dyn Display, usize, PhantomData<Op>);
+impl<Op: Operator> Display for Nested<'_, Op> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "(")?;
+		Op::fmt_formula(f, self.0, &self.1)?;
+		write!(f, ")")
+	}
+}
+
+
+/// A type representing a reduction of multiple lengths into one that can be used as a generic type
+/// argument
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` is not a reduction",
+	label = "expected a reduction",
+	note = "use a predefined reduction like `SumOf` or `MaxOf`"
+)]
+pub trait Reduction: Debug + Default {
+	/// Reduces `lens` into a single length
+	fn reduce(lens: &[usize]) -> Result<usize, ConstraintViolation>;
+	
+	/// Writes the reduction as formula with `lens` as operand (e.g. `sum(lens)`)
+	fn fmt_formula(f: &mut Formatter, lens: &dyn Display) -> fmt::Result {
+		write!(f, "{:?}({})", Self::default(), lens)
+	}
+}
+
+/// A reduction representing the sum of all lengths
+#[derive(Debug, Default)]
+pub struct SumOf;
+impl Reduction for SumOf {
+	fn reduce(lens: &[usize]) -> Result<usize, ConstraintViolation> {
+		lens.iter().try_fold(0usize, |sum, len| sum.checked_add(*len))
+			.ok_or(ConstraintViolation::Overflow)
+	}
+	fn fmt_formula(f: &mut Formatter, lens: &dyn Display) -> fmt::Result {
+		write!(f, "sum({})", lens)
+	}
+}
+
+/// A reduction representing the largest length (or `0` if there are no lengths)
+#[derive(Debug, Default)]
+pub struct MaxOf;
+impl Reduction for MaxOf {
+	fn reduce(lens: &[usize]) -> Result<usize, ConstraintViolation> {
+		Ok(lens.iter().copied().max().unwrap_or_default())
+	}
+	fn fmt_formula(f: &mut Formatter, lens: &dyn Display) -> fmt::Result {
+		write!(f, "max({})", lens)
+	}
+}
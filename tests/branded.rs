@@ -0,0 +1,61 @@
+use len_constraints::{
+	ConstraintViolation,
+	branded::{ self, Branded },
+	constraint::{ Relative, RelativeAtLeast },
+	same_len::SameLen,
+	type_math::{ Add, _0, _16 }
+};
+
+
+/// A consumer that relies on the brand instead of revalidating its arguments
+fn seal<'id>(plaintext: Branded<'id, &[u8], Relative<Add, _0>>,
+	buf: Branded<'id, &mut[u8], Relative<Add, _16>>) -> usize
+{
+	let (plaintext, buf) = (plaintext.slice(), buf.into_inner());
+	buf[..plaintext.len()].copy_from_slice(plaintext);
+	buf.len()
+}
+
+
+#[test]
+fn test_scope() {
+	let (plaintext, buf) = (b"Testolope".as_ref(), &mut[0; 9 + 16]);
+	let written = branded::scope(plaintext, |plaintext, len| {
+		assert_eq!(len.get(), 9);
+		assert_eq!(plaintext.len_token(), len);
+		
+		let buf = Branded::new(buf.as_mut(), len).unwrap();
+		seal(plaintext, buf)
+	});
+	assert_eq!(written, 25);
+	assert_eq!(&buf[..9], b"Testolope");
+}
+#[test]
+fn test_new_err() {
+	let buf = &mut[0; 9 + 15];
+	branded::scope(b"Testolope".as_ref(), |_, len| {
+		let err = Branded::<_, Relative<Add, _16>>::new(buf.as_mut(), len).unwrap_err();
+		assert_eq!(err, ConstraintViolation::relative::<Add, _16>(24, 9).unwrap());
+		
+		let branded = Branded::<_, RelativeAtLeast<Add, _16>>::new(&buf[..], len);
+		assert!(branded.is_err());
+	});
+}
+
+#[test]
+fn test_unbrand() {
+	branded::scope([7u8; 4].as_ref(), |anchor, len| {
+		let other = Branded::<_, Relative<Add, _16>>::new([7u8; 20].as_ref(), len).unwrap();
+		assert_eq!(other.unbrand().slice(len.get()).unwrap().len(), 20);
+		assert_eq!(anchor.into_inner().len(), 4);
+	});
+}
+#[test]
+fn test_same_len() {
+	let (a, b) = ([1u8, 2, 3], [4u8, 5, 6]);
+	let same = branded::scope(&a[..], |a, len| {
+		SameLen::from([a, Branded::new(&b[..], len).unwrap()])
+	});
+	assert_eq!(same.len(), 3);
+	assert_eq!(same.get(2), Some([&3, &6]));
+}
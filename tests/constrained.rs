@@ -0,0 +1,164 @@
+#![cfg(feature = "alloc")]
+
+use len_constraints::{
+	Constrained, ConstraintViolation, Formula,
+	constraint::{ LenConstraint, Fixed, Ranged, RangedInclusive, AtMost, Relative },
+	type_math::{ Sub, Const, _0, _4, _8 }
+};
+use std::{ borrow::Cow, convert::TryFrom, rc::Rc, sync::Arc };
+
+
+macro_rules! v {
+	($len:expr) => (vec![7u8; $len]);
+}
+
+
+#[test]
+fn test_fixed() {
+	Constrained::<Vec<u8>, Fixed<_4>>::try_from(v!(4)).unwrap();
+	Constrained::<Box<[u8]>, Fixed<_4>>::try_from(v!(4).into_boxed_slice()).unwrap();
+	Constrained::<Rc<[u8]>, Fixed<_4>>::try_from(Rc::from(v!(4))).unwrap();
+	Constrained::<Arc<[u8]>, Fixed<_8>>::try_from(Arc::from(v!(8))).unwrap();
+	Constrained::<Cow<[u8]>, Fixed<_8>>::try_from(Cow::from(v!(8))).unwrap();
+}
+#[test]
+fn test_fixed_err() {
+	Constrained::<Vec<u8>, Fixed<_4>>::try_from(v!(3)).unwrap_err();
+	Constrained::<Rc<[u8]>, Fixed<_4>>::try_from(Rc::from(v!(5))).unwrap_err();
+	Constrained::<Arc<[u8]>, Fixed<_8>>::try_from(Arc::from(v!(7))).unwrap_err();
+}
+
+
+#[test]
+fn test_ranged() {
+	let shared = Constrained::<Arc<[u8]>, Ranged<_4, _8>>::try_from(Arc::from(v!(7))).unwrap();
+	assert_eq!(shared.clone().as_ranged().len(), 7);
+	assert_eq!(Arc::<[u8]>::from(shared).len(), 7);
+}
+#[test]
+fn test_ranged_err() {
+	Constrained::<Box<[u8]>, Ranged<_4, _8>>::try_from(v!(3).into_boxed_slice()).unwrap_err();
+	Constrained::<Cow<[u8]>, Ranged<_4, _8>>::try_from(Cow::from(v!(8))).unwrap_err();
+}
+
+
+#[test]
+fn test_relative() {
+	Constrained::<Vec<u8>, Relative<Sub, _4>>::from(v!(4)).slice(8).unwrap();
+	Constrained::<Arc<[u8]>, Relative<Sub, _8>>::try_from(Arc::from(v!(9)), 17).unwrap();
+}
+#[test]
+fn test_relative_err() {
+	Constrained::<Vec<u8>, Relative<Sub, _4>>::from(v!(3)).slice(8).unwrap_err();
+	Constrained::<Arc<[u8]>, Relative<Sub, _8>>::try_from(Arc::from(v!(9)), 16).unwrap_err();
+}
+
+
+/// A third-party constraint that only allows even lengths
+#[derive(Debug, Default)]
+struct Even;
+impl LenConstraint for Even {
+	fn check(len: usize) -> Result<(), ConstraintViolation> {
+		match len % 2 {
+			0 => Ok(()),
+			_ => Err(ConstraintViolation::Fixed{ expected: len + 1, actual: len })
+		}
+	}
+}
+fn check_generic<C: LenConstraint>(len: usize) -> bool {
+	C::check(len).is_ok()
+}
+
+
+#[test]
+fn test_len_constraint() {
+	assert!(check_generic::<Fixed<_4>>(4));
+	assert!(check_generic::<Ranged<_4, _8>>(7));
+	assert!(check_generic::<Even>(8));
+	
+	let mut vec = Constrained::<Vec<u8>, Even>::try_from(v!(4)).unwrap();
+	vec.extend_from_slice(&[1, 2]).unwrap();
+	assert_eq!(vec.len(), 6);
+}
+#[test]
+fn test_len_constraint_err() {
+	assert!(!check_generic::<Fixed<_4>>(5));
+	assert!(!check_generic::<Ranged<_4, _8>>(8));
+	assert!(!check_generic::<Even>(7));
+	
+	let mut vec = Constrained::<Vec<u8>, Even>::try_from(v!(4)).unwrap();
+	vec.push(1).unwrap_err();
+	Constrained::<Arc<[u8]>, Even>::try_from(Arc::from(v!(3))).unwrap_err();
+}
+#[test]
+fn test_len_constraint_loose_bounds() {
+	type Huge = Const<{ usize::MAX }>;
+	assert_eq!(AtMost::<Huge>::capacity_hint(), None);
+	assert_eq!(RangedInclusive::<_0, Huge>::capacity_hint(), None);
+	
+	let vec = Constrained::<Vec<u8>, AtMost<Huge>>::try_from(v!(3)).unwrap();
+	assert!(vec.capacity() < 4096);
+	let vec = Constrained::<Vec<u8>, RangedInclusive<_0, Huge>>::from(
+		Constrained::<&[u8], RangedInclusive<_0, Huge>>::try_from([7; 3].as_ref()).unwrap()
+	);
+	assert!(vec.capacity() < 4096);
+}
+#[test]
+fn test_len_constraint_huge_capacity_hint() {
+	type Huge = Const<{ usize::MAX }>;
+	assert_eq!(Ranged::<_0, Huge>::capacity_hint(), Some(usize::MAX - 1));
+	
+	let vec = Constrained::<Vec<u8>, Ranged<_0, Huge>>::try_from(v!(3)).unwrap();
+	assert_eq!(vec.len(), 3);
+	assert!(vec.capacity() < 4096);
+	let vec = Constrained::<Vec<u8>, Ranged<_0, Huge>>::from(
+		Constrained::<&[u8], Ranged<_0, Huge>>::try_from([7; 3].as_ref()).unwrap()
+	);
+	assert_eq!(vec.len(), 3);
+	assert!(vec.capacity() < 4096);
+}
+
+
+/// A caller-defined error type
+#[derive(Debug, PartialEq, Eq)]
+enum DomainError {
+	InvalidLength(ConstraintViolation)
+}
+impl From<ConstraintViolation> for DomainError {
+	fn from(error: ConstraintViolation) -> Self {
+		DomainError::InvalidLength(error)
+	}
+}
+fn domain_fn(key: &[u8], buf: &mut[u8]) -> Result<usize, DomainError> {
+	let key = Constrained::<&[u8], Fixed<_4>>::try_from_with::<DomainError>(key)?;
+	let buf = Constrained::<&mut[u8], Relative<Sub, _4>>::from(buf);
+	let buf = buf.slice_mut_with::<DomainError>(8)?;
+	Ok(key.len() + buf.len())
+}
+
+
+#[test]
+fn test_try_from_with() {
+	assert_eq!(domain_fn(&[7; 4], &mut [7; 4]), Ok(8));
+}
+#[test]
+fn test_try_from_with_vec() {
+	type Vec64 = Constrained<Vec<u8>, Ranged<_0, Const<64>>>;
+	let expected = Vec64::try_from(v!(4)).unwrap().capacity();
+	assert_eq!(expected, 63);
+	
+	assert_eq!(Vec64::new(v!(4)).unwrap().capacity(), expected);
+	assert_eq!(Vec64::try_from_with::<DomainError>(v!(4)).unwrap().capacity(), expected);
+	let boxed = Constrained::<Box<[u8]>, Ranged<_0, Const<64>>>::new(v!(4).into()).unwrap();
+	assert_eq!(Vec64::from(boxed).capacity(), expected);
+}
+#[test]
+fn test_try_from_with_err() {
+	let fixed = ConstraintViolation::Fixed{ expected: 4, actual: 5 };
+	assert_eq!(domain_fn(&[7; 5], &mut [7; 4]), Err(DomainError::InvalidLength(fixed)));
+	
+	let relative = ConstraintViolation::Relative {
+		expected: 4, actual: 3, relative_to: 8, formula: Formula::of::<Relative<Sub, _4>>()
+	};
+	assert_eq!(domain_fn(&[7; 4], &mut [7; 3]), Err(DomainError::InvalidLength(relative)));
+}
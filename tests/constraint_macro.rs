@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 #[macro_use] extern crate len_constraints;
 use len_constraints::{
 	slice_mut::RelativeMut, slice::{ Fixed, Ranged },
@@ -7,7 +9,7 @@ use std::error::Error;
 
 
 #[test]
-fn test() -> Result<(), Box<Error + 'static>> {
+fn test() -> Result<(), Box<dyn Error + 'static>> {
 	let buf: &mut[u8] = &mut[0; 16];
 	let plaintext: &[u8] = &[];
 	let key: &[u8] = &[0; 32];
@@ -24,19 +26,19 @@ fn test() -> Result<(), Box<Error + 'static>> {
 	
 	assert_eq!(
 		format!("buf: {:?}", buf),
-		"buf: RelativeMut { slice: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], constraint: PhantomData }"
+		"buf: Constrained { inner: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], constraint: PhantomData<len_constraints::constraint::Relative<len_constraints::type_math::Add, len_constraints::type_math::_16>> }"
 	);
 	assert_eq!(
 		format!("plaintext: {:?}", plaintext),
-		"plaintext: Ranged { slice: [], constraint: PhantomData }"
+		"plaintext: Constrained { inner: [], constraint: PhantomData<len_constraints::constraint::Ranged<len_constraints::type_math::_0, len_constraints::type_math::_65536>> }"
 	);
 	assert_eq!(
 		format!("key: {:?}", key),
-		"key: Fixed { slice: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], constraint: PhantomData }"
+		"key: Constrained { inner: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], constraint: PhantomData<len_constraints::constraint::Fixed<len_constraints::type_math::_32>> }"
 	);
 	assert_eq!(
 		format!("nonce: {:?}", nonce),
-		"nonce: Fixed { slice: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], constraint: PhantomData }"
+		"nonce: Constrained { inner: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], constraint: PhantomData<len_constraints::constraint::Fixed<len_constraints::type_math::_12>> }"
 	);
 	Ok(())
 }
@@ -0,0 +1,44 @@
+#![cfg(feature = "generic-array")]
+
+use len_constraints::{
+	TypeNum, owned::FixedArray, slice::Fixed, slice_mut::FixedMut
+};
+use generic_array::GenericArray;
+use std::convert::TryFrom;
+use typenum::{ U0, U12, U32, U1024 };
+
+
+#[test]
+fn test_typenum() {
+	assert_eq!(U0::VALUE, 0);
+	assert_eq!(U12::VALUE, 12);
+	assert_eq!(U1024::VALUE, 1024);
+	
+	Fixed::<u8, U12>::try_from(b"12 byte Nonc".as_ref()).unwrap();
+	Fixed::<u8, U12>::try_from(b"Nonce".as_ref()).unwrap_err();
+}
+
+
+#[test]
+fn test_generic_array() {
+	let array = GenericArray::<u8, U32>::default();
+	let fixed = Fixed::<u8, U32>::from(&array);
+	assert_eq!(fixed.len(), 32);
+	
+	let array: &GenericArray<u8, U32> = fixed.into();
+	assert_eq!(array.as_ptr(), fixed.as_ptr());
+}
+#[test]
+fn test_generic_array_mut() {
+	let mut array = GenericArray::<u8, U32>::default();
+	let mut fixed = FixedMut::<u8, U32>::from(&mut array);
+	fixed[0] = 7;
+	
+	let array: &mut GenericArray<u8, U32> = fixed.into();
+	assert_eq!(array[0], 7);
+}
+#[test]
+fn test_generic_array_storage() {
+	let array = FixedArray::<u8, U12>::try_from(b"12 byte Nonc".as_ref()).unwrap();
+	assert_eq!(array.into_inner().as_slice(), b"12 byte Nonc");
+}
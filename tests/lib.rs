@@ -1,45 +1,190 @@
+#[macro_use] extern crate len_constraints;
 use len_constraints::{
-	ConstraintViolation,
-	type_math::{ Sub, _4, _8 }
+	ConstraintViolation, RelativeLens, Formula,
+	constraint::{ Relative, RelativeAtLeast, RelativeMulti },
+	type_math::{ Add, Sub, Mul, CeilDiv, Then, SumOf, MaxOf, _4, _8, _16 }
 };
+#[cfg(feature = "std")]
+use std::io;
 
 
 #[test]
 fn test_constraint_violation_fixed() {
-	assert_eq!(ConstraintViolation::fixed::<_8>(7).by, -1);
-	assert_eq!(ConstraintViolation::fixed::<_8>(9).by,  1);
+	assert_eq!(ConstraintViolation::fixed::<_8>(7).unwrap().by(), Some(-1));
+	assert_eq!(ConstraintViolation::fixed::<_8>(9).unwrap().by(), Some( 1));
 }
-#[test] #[should_panic]
-fn test_constraint_violation_fixed_panic() {
-	ConstraintViolation::fixed::<_8>(8);
+#[test]
+fn test_constraint_violation_fixed_none() {
+	assert_eq!(ConstraintViolation::fixed::<_8>(8), None);
 }
 
 
 #[test]
 fn test_constraint_violation_range() {
-	assert_eq!(ConstraintViolation::ranged::<_4, _8>(3).by, -1);
-	assert_eq!(ConstraintViolation::ranged::<_4, _8>(8).by,  1);
+	assert_eq!(ConstraintViolation::ranged::<_4, _8>(3).unwrap().by(), Some(-1));
+	assert_eq!(ConstraintViolation::ranged::<_4, _8>(8).unwrap().by(), Some( 1));
+}
+#[test]
+fn test_constraint_violation_range_none() {
+	assert_eq!(ConstraintViolation::ranged::<_4, _8>(4), None);
+	assert_eq!(ConstraintViolation::ranged::<_4, _8>(7), None);
 }
-#[test] #[should_panic]
-fn test_constraint_violation_range_panic_0() {
-	assert_eq!(ConstraintViolation::ranged::<_4, _8>(4).by, -1);
+
+
+#[test]
+fn test_constraint_violation_range_inclusive() {
+	assert_eq!(ConstraintViolation::ranged_inclusive::<_4, _8>(3).unwrap().by(), Some(-1));
+	assert_eq!(ConstraintViolation::ranged_inclusive::<_4, _8>(9).unwrap().by(), Some( 1));
+	assert_eq!(ConstraintViolation::ranged_inclusive::<_4, _8>(4), None);
+	assert_eq!(ConstraintViolation::ranged_inclusive::<_4, _8>(8), None);
 }
-#[test] #[should_panic]
-fn test_constraint_violation_range_panic_1() {
-	assert_eq!(ConstraintViolation::ranged::<_4, _8>(7).by, 1);
+#[test]
+fn test_constraint_violation_bounds() {
+	assert_eq!(ConstraintViolation::at_least::<_8>(7).unwrap().by(), Some(-1));
+	assert_eq!(ConstraintViolation::at_least::<_8>(8), None);
+	assert_eq!(ConstraintViolation::at_most::<_8>(9).unwrap().by(), Some( 1));
+	assert_eq!(ConstraintViolation::at_most::<_8>(8), None);
 }
 
 
 #[test]
 fn test_constraint_violation_relative() {
-	assert_eq!(ConstraintViolation::relative::<Sub, _4>(2, 7).by, -1);
-	assert_eq!(ConstraintViolation::relative::<Sub, _4>(4, 7).by,  1);
+	assert_eq!(ConstraintViolation::relative::<Sub, _4>(2, 7).unwrap().by(), Some(-1));
+	assert_eq!(ConstraintViolation::relative::<Sub, _4>(4, 7).unwrap().by(), Some( 1));
+}
+#[test]
+fn test_constraint_violation_relative_none() {
+	assert_eq!(ConstraintViolation::relative::<Sub, _4>(3, 7), None);
+}
+#[test]
+fn test_constraint_violation_relative_arithmetic() {
+	let underflow = ConstraintViolation::relative::<Sub, _4>(0xDEAD, 3);
+	assert_eq!(underflow, Some(ConstraintViolation::Underflow));
+	let overflow = ConstraintViolation::relative::<Add, _4>(7, usize::MAX);
+	assert_eq!(overflow, Some(ConstraintViolation::Overflow));
+}
+#[test]
+fn test_constraint_violation_relative_multi() {
+	let violation = ConstraintViolation::relative_multi::<SumOf, Add, _16, 2>(40, [4, 16]);
+	assert_eq!(violation.unwrap().by(), Some(4));
+	assert_eq!(ConstraintViolation::relative_multi::<SumOf, Add, _16, 2>(36, [4, 16]), None);
+	assert_eq!(ConstraintViolation::relative_multi::<MaxOf, Add, _16, 3>(36, [4, 20, 7]), None);
+	
+	let overflow = ConstraintViolation::relative_multi::<SumOf, Add, _4, 2>(7, [usize::MAX, 1]);
+	assert_eq!(overflow, Some(ConstraintViolation::Overflow));
+}
+#[test]
+fn test_constraint_violation_relative_inequality() {
+	assert_eq!(ConstraintViolation::relative_at_least::<Add, _4>(6, 3).unwrap().by(), Some(-1));
+	assert_eq!(ConstraintViolation::relative_at_least::<Add, _4>(4096, 3), None);
+	assert_eq!(ConstraintViolation::relative_at_most::<Add, _4>(8, 3).unwrap().by(), Some(1));
+	assert_eq!(ConstraintViolation::relative_at_most::<Add, _4>(0, 3), None);
+	assert_eq!(ConstraintViolation::relative_within::<Add, _8, _4>(16, 3).unwrap().by(), Some(1));
+	assert_eq!(ConstraintViolation::relative_within::<Add, _8, _4>(7, 3), None);
+	let underflow = ConstraintViolation::relative_within::<Sub, _8, _4>(0, 3);
+	assert_eq!(underflow, Some(ConstraintViolation::Underflow));
+}
+
+
+#[test]
+fn test_formula_eq() {
+	assert_eq!(Formula::of::<Relative<Sub, _4>>(), Formula::of::<Relative<Sub, _4>>());
+	assert_eq!(Formula::of::<Relative<Sub, _4>>(), Formula::of::<Relative<Sub, tn!(4)>>());
+	assert_ne!(Formula::of::<Relative<Sub, _4>>(), Formula::of::<Relative<Mul, _4>>());
+	assert_ne!(Formula::of::<Relative<Add, _4>>(), Formula::of::<Relative<Add, _16>>());
+	assert_ne!(Formula::of::<Relative<Add, _16>>(), Formula::of::<RelativeAtLeast<Add, _16>>());
+	
+	let add = ConstraintViolation::relative::<Add, _4>(2, 7).unwrap();
+	let mul = ConstraintViolation::Relative {
+		expected: 11, actual: 2, relative_to: 7, formula: Formula::of::<Relative<Mul, _4>>()
+	};
+	assert_ne!(add, mul);
+}
+#[test]
+fn test_constraint_violation_fields() {
+	assert_eq!(
+		ConstraintViolation::relative::<Sub, _4>(2, 7),
+		Some(ConstraintViolation::Relative {
+			expected: 3, actual: 2, relative_to: 7, formula: Formula::of::<Relative<Sub, _4>>()
+		})
+	);
+	assert_eq!(
+		ConstraintViolation::relative::<Then<CeilDiv, tn!(3), Mul>, _4>(13, 8).unwrap().to_string(),
+		"The length constraint `len == (ceil(relative_to / 3)) * 4` (`len == 12` relative to 8) \
+			was violated by +1"
+	);
+	assert_eq!(
+		ConstraintViolation::ranged::<_4, _8>(9).unwrap().to_string(),
+		"The length constraint `4 <= len < 8` was violated by +2"
+	);
+	assert_eq!(
+		ConstraintViolation::ranged_inclusive::<_4, _8>(9).unwrap().to_string(),
+		"The length constraint `4 <= len <= 8` was violated by +1"
+	);
+	assert_eq!(
+		ConstraintViolation::at_least::<_16>(4).unwrap().to_string(),
+		"The length constraint `len >= 16` was violated by -12"
+	);
+	assert_eq!(
+		ConstraintViolation::at_most::<_8>(9),
+		Some(ConstraintViolation::AtMost{ max: 8, actual: 9 })
+	);
+	assert_eq!(
+		ConstraintViolation::at_most::<_8>(9).unwrap().to_string(),
+		"The length constraint `len <= 8` was violated by +1"
+	);
+	assert_eq!(
+		ConstraintViolation::relative_at_least::<Add, _4>(2, 7),
+		Some(ConstraintViolation::RelativeAtLeast {
+			min: 11, actual: 2, relative_to: 7, formula: Formula::of::<RelativeAtLeast<Add, _4>>()
+		})
+	);
+	assert_eq!(
+		ConstraintViolation::relative_at_least::<Add, _16>(18, 9).unwrap().to_string(),
+		"The length constraint `len >= relative_to + 16` (`len >= 25` relative to 9) was violated \
+			by -7"
+	);
+	assert_eq!(
+		ConstraintViolation::relative_at_most::<Add, _4>(8, 3).unwrap().to_string(),
+		"The length constraint `len <= relative_to + 4` (`len <= 7` relative to 3) was violated \
+			by +1"
+	);
+	assert_eq!(
+		ConstraintViolation::relative_multi::<SumOf, Add, _16, 2>(40, [4, 16]),
+		Some(ConstraintViolation::RelativeMulti {
+			expected: 36, actual: 40, relative_to: RelativeLens::new([4, 16]),
+			formula: Formula::of::<RelativeMulti<SumOf, Add, _16>>()
+		})
+	);
+	assert_eq!(
+		ConstraintViolation::relative_multi::<SumOf, Add, _16, 2>(40, [4, 16]).unwrap().to_string(),
+		"The length constraint `len == sum(relative_to) + 16` (`len == 36` relative to [4, 16]) \
+			was violated by +4"
+	);
+	assert_eq!(RelativeLens::new([4, 16]).as_slice(), &[4, 16]);
+	assert_eq!(RelativeLens::new([4, 16, 7]).as_slice(), &[4, 16, 7]);
+	assert_eq!(
+		ConstraintViolation::relative_multi::<SumOf, Add, _16, 5>(40, [4, 16, 7, 1, 2]).unwrap()
+			.to_string(),
+		"The length constraint `len == sum(relative_to) + 16` (`len == 46` relative to \
+			[4, 16, 7, … (5 in total)]) was violated by -6"
+	);
+	let lens = RelativeLens::new([4, 16, 7, 1, 2]);
+	assert_eq!((lens.as_slice(), lens.count()), (&[4, 16, 7][..], 5));
+	assert_ne!(lens, RelativeLens::new([4, 16, 7, 1]));
+	assert_eq!(
+		ConstraintViolation::relative_within::<Add, _8, _4>(0, 8).unwrap().to_string(),
+		"The length constraint `|len - (relative_to + 8)| <= 4` (`12 <= len <= 20` relative to 8) \
+			was violated by -12"
+	);
 }
-#[test] #[should_panic]
-fn test_constraint_violation_relative_panic_0() {
-	ConstraintViolation::relative::<Sub, _4>(0xDEAD, 3);
+#[test]
+fn test_constraint_violation_size() {
+	assert!(std::mem::size_of::<ConstraintViolation>() <= 8 * std::mem::size_of::<usize>());
+}
+#[test]
+#[cfg(feature = "std")]
+fn test_constraint_violation_io() {
+	let error = io::Error::from(ConstraintViolation::fixed::<_8>(7).unwrap());
+	assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
 }
-#[test] #[should_panic]
-fn test_constraint_violation_relative_panic_1() {
-	ConstraintViolation::relative::<Sub, _4>(3, 7);
-}
\ No newline at end of file
@@ -0,0 +1,180 @@
+#![cfg(feature = "alloc")]
+
+use len_constraints::{
+	owned::{
+		FixedArray, FixedBox, FixedVec, RangedVec, RangedArray,
+		FixedArrayN, FixedVecN, RangedVecN, RangedArrayN
+	},
+	slice::Fixed,
+	type_math::{ TypeNum, ArrayLen, _0, _4, _8 }
+};
+use std::convert::TryFrom;
+
+
+macro_rules! v {
+	($len:expr) => (vec![7u8; $len]);
+}
+
+
+#[test]
+fn test_fixed_array() {
+	let mut array = FixedArray::<u8, _4>::try_from(v!(4).as_slice()).unwrap();
+	array.as_fixed_mut()[3] = 4;
+	assert_eq!(array.as_fixed().slice(), &[7, 7, 7, 4]);
+	assert_eq!(array.into_inner(), [7, 7, 7, 4]);
+	
+	let array = FixedArray::<u8, _8>::new([7; 8]);
+	assert_eq!(array.len(), 8);
+}
+#[test]
+fn test_fixed_array_err() {
+	FixedArray::<u8, _4>::try_from(v!(3).as_slice()).unwrap_err();
+	FixedArray::<u8, _4>::try_from(v!(5).as_slice()).unwrap_err();
+}
+
+
+#[test]
+fn test_fixed_box() {
+	let mut boxed = FixedBox::<u8, _4>::try_from(v!(4).into_boxed_slice()).unwrap();
+	boxed.as_fixed_mut()[0] = 4;
+	assert_eq!(boxed.as_fixed().slice(), &[4, 7, 7, 7]);
+	assert_eq!(Box::<[u8]>::from(boxed).len(), 4);
+}
+#[test]
+fn test_fixed_box_err() {
+	FixedBox::<u8, _8>::try_from(v!(7).into_boxed_slice()).unwrap_err();
+	FixedBox::<u8, _8>::try_from(v!(9).into_boxed_slice()).unwrap_err();
+}
+
+
+#[test]
+fn test_fixed_vec() {
+	let mut vec = FixedVec::<u8, _8>::try_from(v!(8)).unwrap();
+	vec.as_fixed_mut()[7] = 4;
+	assert_eq!(vec.as_fixed().slice(), &[7, 7, 7, 7, 7, 7, 7, 4]);
+	
+	let slice = v!(8);
+	let fixed = Fixed::<u8, _8>::try_from(slice.as_slice()).unwrap();
+	assert_eq!(Vec::from(FixedVec::from(fixed)), v!(8));
+}
+#[test]
+fn test_fixed_vec_err() {
+	FixedVec::<u8, _8>::try_from(v!(0)).unwrap_err();
+	FixedVec::<u8, _8>::try_from(v!(9)).unwrap_err();
+}
+
+
+#[test]
+fn test_ranged_vec() {
+	let mut vec = RangedVec::<u8, _4, _8>::try_from(v!(4)).unwrap();
+	assert!(vec.capacity() >= 7);
+	
+	vec.push(1).unwrap();
+	vec.insert(0, 2).unwrap();
+	vec.extend_from_slice(&[3]).unwrap();
+	assert_eq!(vec.as_ranged().slice(), &[2, 7, 7, 7, 7, 1, 3]);
+	
+	assert_eq!(vec.pop().unwrap(), Some(3));
+	assert_eq!(vec.drain(..2).unwrap(), vec![2, 7]);
+	vec.truncate(9).unwrap();
+	assert_eq!(Vec::from(vec), vec![7, 7, 7, 1]);
+}
+#[test]
+fn test_ranged_vec_err() {
+	RangedVec::<u8, _4, _8>::try_from(v!(3)).unwrap_err();
+	RangedVec::<u8, _4, _8>::try_from(v!(8)).unwrap_err();
+	
+	let mut vec = RangedVec::<u8, _4, _8>::try_from(v!(7)).unwrap();
+	vec.push(1).unwrap_err();
+	vec.insert(0, 1).unwrap_err();
+	vec.extend_from_slice(&[1]).unwrap_err();
+	vec.truncate(3).unwrap_err();
+	vec.drain(1..5).unwrap_err();
+	assert_eq!(vec.len(), 7);
+	
+	let mut vec = RangedVec::<u8, _4, _8>::try_from(v!(4)).unwrap();
+	vec.pop().unwrap_err();
+	vec.drain(..).unwrap_err();
+	assert_eq!(vec.len(), 4);
+}
+#[test]
+fn test_vec_drain_leak() {
+	let mut vec = FixedVec::<u8, _4>::try_from(v!(4)).unwrap();
+	std::mem::forget(vec.drain(0..0).unwrap());
+	assert_eq!(vec.as_fixed().len(), 4);
+	
+	let mut vec = RangedVec::<u8, _4, _8>::try_from(v!(7)).unwrap();
+	std::mem::forget(vec.drain(..3).unwrap());
+	assert_eq!(vec.as_ranged().len(), 4);
+}
+
+
+#[test]
+fn test_ranged_array() {
+	let mut array = RangedArray::<u8, _4, _8>::try_from(v!(4).as_slice()).unwrap();
+	
+	array.push(1).unwrap();
+	array.insert(0, 2).unwrap();
+	array.extend_from_slice(&[3]).unwrap();
+	assert_eq!(array.as_ranged().slice(), &[2, 7, 7, 7, 7, 1, 3]);
+	
+	assert_eq!(array.pop().unwrap(), Some(3));
+	array.truncate(4).unwrap();
+	array.as_ranged_mut()[3] = 4;
+	assert_eq!(&*array, &[2, 7, 7, 4]);
+}
+#[test]
+fn test_ranged_array_new() {
+	let mut array = RangedArray::<u8, _0, _4>::new();
+	assert!(array.is_empty());
+	array.extend_from_slice(&[1, 2, 3]).unwrap();
+	array.push(4).unwrap_err();
+	assert_eq!(&*array, &[1, 2, 3]);
+	
+	let mut array = RangedArrayN::<String, 0, 3>::default();
+	array.push(String::from("a")).unwrap();
+	assert_eq!(array.pop().unwrap().as_deref(), Some("a"));
+	assert_eq!(array.pop().unwrap(), None);
+}
+#[test]
+fn test_ranged_array_lend() {
+	/// Lends the array out without requiring `T: Default`
+	fn lend<T, Start: TypeNum, End: ArrayLen<T>>(array: &mut RangedArray<T, Start, End>) -> usize {
+		array.as_ranged_mut().len() + array.as_ranged().len()
+	}
+	
+	let mut array = RangedArray::<u8, _4, _8>::try_from(v!(5).as_slice()).unwrap();
+	assert_eq!(lend(&mut array), 10);
+}
+#[test]
+fn test_ranged_array_err() {
+	RangedArray::<u8, _4, _8>::try_from(v!(3).as_slice()).unwrap_err();
+	RangedArray::<u8, _4, _8>::try_from(v!(8).as_slice()).unwrap_err();
+	
+	let mut array = RangedArray::<u8, _4, _8>::try_from(v!(7).as_slice()).unwrap();
+	array.push(1).unwrap_err();
+	array.insert(0, 1).unwrap_err();
+	array.extend_from_slice(&[1]).unwrap_err();
+	array.truncate(3).unwrap_err();
+	assert_eq!(array.len(), 7);
+	
+	let mut array = RangedArray::<u8, _4, _8>::try_from(v!(4).as_slice()).unwrap();
+	array.pop().unwrap_err();
+	assert_eq!(array.len(), 4);
+}
+
+
+#[test]
+fn test_const_generic() {
+	let array = FixedArrayN::<u8, 20>::try_from(v!(20).as_slice()).unwrap();
+	assert_eq!(array.into_inner(), [7; 20]);
+	FixedVecN::<u8, 1500>::try_from(v!(1500)).unwrap();
+	
+	let mut vec = RangedVecN::<u8, 0, 3>::try_from(v!(1)).unwrap();
+	vec.push(1).unwrap();
+	vec.push(1).unwrap_err();
+	
+	let mut array = RangedArrayN::<u8, 0, 3>::try_from(v!(1).as_slice()).unwrap();
+	array.push(1).unwrap();
+	array.push(1).unwrap_err();
+}
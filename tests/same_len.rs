@@ -0,0 +1,95 @@
+use len_constraints::{
+	ConstraintViolation,
+	same_len::{ SameLen, SameLenInOut },
+	type_math::{ Add, _0 }
+};
+
+
+#[test]
+fn test_new() {
+	let (a, b, c) = ([1u8; 16], [2u8; 16], [3u8; 16]);
+	let same = SameLen::new([a.as_ref(), b.as_ref(), c.as_ref()]).unwrap();
+	assert_eq!(same.len(), 16);
+	assert!(!same.is_empty());
+	
+	let empty = SameLen::<&[u8], 0>::new([]).unwrap();
+	assert!(empty.is_empty());
+}
+#[test]
+fn test_new_err() {
+	let (a, b) = ([1u8; 16], [2u8; 15]);
+	let err = SameLen::new([a.as_ref(), b.as_ref()]).unwrap_err();
+	assert_eq!(err, ConstraintViolation::relative::<Add, _0>(15, 16).unwrap());
+}
+
+#[test]
+fn test_get() {
+	let (a, b) = ([1u8, 2, 3], [4u8, 5, 6]);
+	let same = SameLen::new([a.as_ref(), b.as_ref()]).unwrap();
+	assert_eq!(same.get(1), Some([&2, &5]));
+	assert_eq!(same.get(3), None);
+}
+#[test]
+fn test_get_mut() {
+	let (a, b) = (&mut[1u8, 2, 3], &mut[4u8, 5, 6]);
+	let mut same = SameLen::new([a.as_mut(), b.as_mut()]).unwrap();
+	let [x, y] = same.get_mut(2).unwrap();
+	std::mem::swap(x, y);
+	assert!(same.get_mut(3).is_none());
+	
+	assert_eq!(a, &[1, 2, 6]);
+	assert_eq!(b, &[4, 5, 3]);
+}
+
+#[test]
+fn test_iter() {
+	let (a, b) = ([1u8, 2, 3], [4u8, 5, 6]);
+	let same = SameLen::new([a.as_ref(), b.as_ref()]).unwrap();
+	
+	let iter = same.iter();
+	assert_eq!(iter.len(), 3);
+	let sums: Vec<u8> = iter.map(|[x, y]| x + y).collect();
+	assert_eq!(sums, [5, 7, 9]);
+}
+#[test]
+fn test_iter_mut() {
+	let (a, b) = (&mut[1u8, 2, 3], &mut[4u8, 5, 6]);
+	let mut same = SameLen::new([a.as_mut(), b.as_mut()]).unwrap();
+	
+	let iter = same.iter_mut();
+	assert_eq!(iter.len(), 3);
+	iter.for_each(|[a, b]| std::mem::swap(a, b));
+	assert_eq!(a, &[4, 5, 6]);
+	assert_eq!(b, &[1, 2, 3]);
+}
+
+
+#[test]
+fn test_in_out() {
+	let (data, key, out) = ([0x0fu8; 8], [0xffu8; 8], &mut[0u8; 8]);
+	let mut same = SameLenInOut::new([&data[..], &key[..]], [out.as_mut()]).unwrap();
+	assert_eq!(same.len(), 8);
+	
+	let iter = same.iter_mut();
+	assert_eq!(iter.len(), 8);
+	iter.for_each(|([data, key], [out])| *out = data ^ key);
+	assert_eq!(out, &[0xf0; 8]);
+}
+#[test]
+fn test_in_out_get_mut() {
+	let (data, out) = ([1u8, 2, 3], &mut[false; 3]);
+	let mut same = SameLenInOut::new([&data[..]], [out.as_mut()]).unwrap();
+	let ([value], [is_two]) = same.get_mut(1).unwrap();
+	*is_two = *value == 2;
+	assert!(same.get_mut(3).is_none());
+	assert_eq!(out, &[false, true, false]);
+}
+#[test]
+fn test_in_out_err() {
+	let (data, out) = ([1u8; 16], &mut[0u8; 15]);
+	let err = SameLenInOut::new([&data[..]], [out.as_mut()]).unwrap_err();
+	assert_eq!(err, ConstraintViolation::relative::<Add, _0>(15, 16).unwrap());
+	
+	let (a, b) = (&mut[0u8; 4], &mut[0u8; 5]);
+	SameLenInOut::<u8, u8, 0, 2>::new([], [a.as_mut(), b.as_mut()]).unwrap_err();
+}
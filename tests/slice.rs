@@ -1,6 +1,11 @@
 use len_constraints::{
-	slice::{ Fixed, Ranged, Relative },
-	type_math::{ Sub, _4, _8 }
+	slice::{
+		Fixed, Ranged, RangedInclusive, AtLeast, AtMost, Relative,
+		RelativeAtLeast, RelativeAtMost, RelativeWithin, RelativeMulti,
+		FixedN, RangedN, RangedInclusiveN, AtLeastN, AtMostN, RelativeN
+	},
+	constraint,
+	type_math::{ Add, Sub, Mul, CeilDiv, Then, Sum, SumOf, Const, _4, _8, _16, _32 }
 };
 use std::convert::TryFrom;
 
@@ -35,6 +40,23 @@ fn test_ranged_err() {
 	Ranged::<u8, _4, _8>::try_from(s!(8)).unwrap_err();
 }
 
+#[test]
+fn test_ranged_inclusive() {
+	RangedInclusive::<u8, _4, _8>::try_from(s!(4)).unwrap();
+	RangedInclusive::<u8, _4, _8>::try_from(s!(8)).unwrap();
+	RangedInclusive::<u8, _4, _8>::try_from(s!(3)).unwrap_err();
+	RangedInclusive::<u8, _4, _8>::try_from(s!(9)).unwrap_err();
+}
+#[test]
+fn test_bounds() {
+	AtLeast::<u8, _16>::try_from(s!(16)).unwrap();
+	AtLeast::<u8, _16>::try_from(s!(4096)).unwrap();
+	AtLeast::<u8, _16>::try_from(s!(15)).unwrap_err();
+	AtMost::<u8, _16>::try_from(s!(0)).unwrap();
+	AtMost::<u8, _16>::try_from(s!(16)).unwrap();
+	AtMost::<u8, _16>::try_from(s!(17)).unwrap_err();
+}
+
 
 #[test]
 fn test_relative() {
@@ -46,4 +68,69 @@ fn test_relative_err() {
 	Relative::<u8, Sub, _4>::from(s!(0)).slice(3).unwrap_err();
 	Relative::<u8, Sub, _4>::from(s!(3)).slice(8).unwrap_err();
 	Relative::<u8, Sub, _8>::from(s!(9)).slice(16).unwrap_err();
-}
\ No newline at end of file
+}
+#[test]
+fn test_relative_from() {
+	RelativeAtLeast::<u8, Add, _4>::from(s!(16)).slice(8).unwrap();
+	RelativeAtMost::<u8, Add, _4>::from(s!(8)).slice(8).unwrap();
+	RelativeWithin::<u8, Add, _4, _4>::from(s!(16)).slice(8).unwrap();
+	RelativeMulti::<u8, SumOf, Add, _4>::from(s!(16)).slice([4, 8]).unwrap();
+	
+	RelativeAtLeast::<u8, Add, _4>::from(s!(8)).slice(8).unwrap_err();
+	RelativeAtMost::<u8, Add, _4>::from(s!(16)).slice(8).unwrap_err();
+	RelativeWithin::<u8, Add, _4, _4>::from(s!(17)).slice(8).unwrap_err();
+	RelativeMulti::<u8, SumOf, Add, _4>::from(s!(16)).slice([4, 4]).unwrap_err();
+}
+
+
+#[test]
+fn test_const_generic() {
+	FixedN::<u8, 20>::try_from(s!(20)).unwrap();
+	FixedN::<u8, 20>::try_from(s!(19)).unwrap_err();
+	RangedN::<u8, 1500, 1501>::try_from(s!(1500)).unwrap();
+	RangedN::<u8, 1500, 1501>::try_from(s!(1501)).unwrap_err();
+	RangedInclusiveN::<u8, 1500, 1501>::try_from(s!(1501)).unwrap();
+	AtLeastN::<u8, 20>::try_from(s!(19)).unwrap_err();
+	AtMostN::<u8, 65535>::try_from(s!(1500)).unwrap();
+	RelativeN::<u8, Sub, 20>::from(s!(4)).slice(24).unwrap();
+	RelativeN::<u8, Sub, 20>::from(s!(4)).slice(25).unwrap_err();
+}
+
+
+#[test]
+fn test_split() {
+	let (prefix, tail) = Relative::<u8, Sub, _4>::from(s!(9)).split(8).unwrap();
+	assert_eq!((prefix.len(), tail.len()), (4, 5));
+	Relative::<u8, Sub, _4>::from(s!(3)).split(8).unwrap_err();
+}
+#[test]
+fn test_type_arithmetic() {
+	Fixed::<u8, Sum<_8, _4>>::try_from(s!(12)).unwrap();
+	Fixed::<u8, Sum<_8, _4>>::try_from(s!(8)).unwrap_err();
+}
+
+
+#[test]
+fn test_widening() {
+	let fixed = Fixed::<u8, _32>::try_from(s!(32)).unwrap();
+	let ranged = fixed.into_ranged::<_16, Const<33>>();
+	assert_eq!(ranged.len(), 32);
+	
+	let ranged = ranged.widen::<_8, Sum<_32, _8>>();
+	assert_eq!(ranged.slice(), s!(32));
+}
+
+
+#[test]
+fn test_formula() {
+	type Base64 = Then<CeilDiv, Const<3>, Mul>;
+	Relative::<u8, Base64, _4>::from(s!(8)).slice(5).unwrap();
+	Relative::<u8, Base64, _4>::from(s!(8)).slice(7).unwrap_err();
+	
+	let formula = format!("{:?}", constraint::Relative::<Base64, _4>::default());
+	assert_eq!(formula, "len == (ceil(relative_to / 3)) * 4");
+	let formula = format!("{:?}", constraint::RelativeWithin::<Add, _16, _4>::default());
+	assert_eq!(formula, "|len - (relative_to + 16)| <= 4");
+	let formula = format!("{:?}", constraint::RelativeMulti::<SumOf, Add, _16>::default());
+	assert_eq!(formula, "len == sum(relative_to) + 16");
+}
@@ -1,6 +1,10 @@
 use len_constraints::{
-	slice_mut::{ FixedMut, RangedMut, RelativeMut },
-	type_math::{ Sub, _4, _8 }
+	slice::Ranged,
+	slice_mut::{
+		FixedMut, RangedMut, RangedInclusiveMut, AtLeastMut, AtMostMut, RelativeMut,
+		RelativeAtLeastMut, RelativeAtMostMut, RelativeWithinMut, RelativeMultiMut
+	},
+	type_math::{ Add, Sub, Mul, SumOf, _0, _4, _8, _16, _65536 }
 };
 use std::convert::TryFrom;
 
@@ -35,6 +39,20 @@ fn test_ranged_mut_err() {
 	RangedMut::<u8, _4, _8>::try_from(s!(8)).unwrap_err();
 }
 
+#[test]
+fn test_ranged_inclusive_mut() {
+	RangedInclusiveMut::<u8, _4, _8>::try_from(s!(4)).unwrap();
+	RangedInclusiveMut::<u8, _4, _8>::try_from(s!(8)).unwrap();
+	RangedInclusiveMut::<u8, _4, _8>::try_from(s!(9)).unwrap_err();
+}
+#[test]
+fn test_bounds_mut() {
+	AtLeastMut::<u8, _16>::try_from(s!(4096)).unwrap();
+	AtLeastMut::<u8, _16>::try_from(s!(15)).unwrap_err();
+	AtMostMut::<u8, _16>::try_from(s!(16)).unwrap();
+	AtMostMut::<u8, _16>::try_from(s!(17)).unwrap_err();
+}
+
 
 #[test]
 fn test_relative_mut() {
@@ -46,4 +64,96 @@ fn test_relative_mut_err() {
 	RelativeMut::<u8, Sub, _4>::from(s!(0)).slice_mut(3).unwrap_err();
 	RelativeMut::<u8, Sub, _4>::from(s!(3)).slice_mut(8).unwrap_err();
 	RelativeMut::<u8, Sub, _8>::from(s!(9)).slice_mut(16).unwrap_err();
-}
\ No newline at end of file
+}
+
+
+#[test]
+fn test_relative_inequality_mut() {
+	let buf = &mut[7; 4096];
+	assert_eq!(RelativeAtLeastMut::<u8, Add, _16>::new(buf).slice_mut(9).unwrap().len(), 4096);
+	RelativeAtMostMut::<u8, Sub, _4>::new(s!(0)).slice_mut(8).unwrap();
+	RelativeWithinMut::<u8, Add, _0, _4>::new(s!(12)).slice_mut(16).unwrap();
+}
+#[test]
+fn test_relative_inequality_mut_err() {
+	RelativeAtLeastMut::<u8, Add, _16>::new(s!(24)).slice_mut(9).unwrap_err();
+	RelativeAtMostMut::<u8, Sub, _4>::new(s!(5)).slice_mut(8).unwrap_err();
+	RelativeWithinMut::<u8, Add, _0, _4>::new(s!(11)).slice_mut(16).unwrap_err();
+	RelativeWithinMut::<u8, Add, _0, _4>::try_from(s!(21), 16).unwrap_err();
+}
+
+
+#[test]
+fn test_split_mut() {
+	let scratch = &mut[7; 4096];
+	let (buf, tail) = RelativeMut::<u8, Add, _16>::from(&mut scratch[..]).split_mut(9).unwrap();
+	assert_eq!((buf.len(), tail.len()), (25, 4071));
+	
+	let scratch = &mut[7; 25];
+	let (buf, tail) = RelativeAtLeastMut::<u8, Add, _16>::new(scratch).split_mut(9).unwrap();
+	assert_eq!((buf.len(), tail.len()), (25, 0));
+}
+#[test]
+fn test_split_mut_err() {
+	RelativeMut::<u8, Add, _16>::from(s!(24)).split_mut(9).unwrap_err();
+	RelativeAtLeastMut::<u8, Sub, _16>::new(s!(24)).split_mut(9).unwrap_err();
+}
+
+
+#[test]
+fn test_inverse_mut() {
+	let scratch = &mut[7; 4096];
+	let buf = RelativeAtLeastMut::<u8, Add, _16>::new(scratch);
+	assert_eq!(buf.max_relative_to(), Some(4080));
+	
+	let scratch = &mut[7; 22];
+	let buf = RelativeMut::<u8, Mul, _4>::from(&mut scratch[..]);
+	assert_eq!(buf.max_relative_to(), Some(5));
+	assert_eq!(buf.required_relative_to(), Some(6));
+	
+	let scratch = &mut[7; 8];
+	let buf = RelativeMut::<u8, Add, _16>::from(&mut scratch[..]);
+	assert_eq!(buf.max_relative_to(), None);
+	assert_eq!(buf.required_relative_to(), Some(0));
+}
+
+
+#[test]
+fn test_relative_multi_mut() {
+	let (header, body, envelope) = (&[0u8; 4], &[0u8; 9], &mut[0u8; 29]);
+	let envelope = RelativeMultiMut::<u8, SumOf, Add, _16>::new(envelope)
+		.slice_mut([header.len(), body.len()]).unwrap();
+	assert_eq!(envelope.len(), 29);
+	RelativeMultiMut::<u8, SumOf, Add, _16>::try_from(s!(29), [4, 9]).unwrap();
+	
+	// Additional authenticated data, nonce, header and body
+	RelativeMultiMut::<u8, SumOf, Add, _16>::try_from(s!(57), [16, 12, 4, 9]).unwrap();
+}
+#[test]
+fn test_relative_multi_mut_err() {
+	RelativeMultiMut::<u8, SumOf, Add, _16>::new(s!(28)).slice_mut([4, 9]).unwrap_err();
+	RelativeMultiMut::<u8, SumOf, Add, _16>::try_from(s!(30), [4, 9]).unwrap_err();
+	RelativeMultiMut::<u8, SumOf, Add, _16>::new(s!(56)).slice_mut([16, 12, 4, 9]).unwrap_err();
+}
+
+
+#[test]
+fn test_relative_to() {
+	let plaintext = Ranged::<u8, _0, _65536>::try_from(b"Testolope".as_ref()).unwrap();
+	let buf = &mut[7; 25];
+	let mut pair = RelativeMut::<u8, Add, _16>::from(&mut buf[..]).relative_to(&plaintext).unwrap();
+	pair.slice_mut()[0] = 4;
+	assert_eq!(pair.slice()[0], 4);
+	assert_eq!(pair.anchor().slice(), b"Testolope");
+	
+	let buf = &mut[7; 32];
+	let (buf, anchor) = RelativeAtLeastMut::<u8, Add, _16>::new(buf)
+		.relative_to(b"Testolope").unwrap().into_inner();
+	assert_eq!((buf.len(), anchor.len()), (32, 9));
+}
+#[test]
+fn test_relative_to_err() {
+	let plaintext = Ranged::<u8, _0, _65536>::try_from(b"Testolope".as_ref()).unwrap();
+	RelativeMut::<u8, Add, _16>::from(s!(24)).relative_to(&plaintext).unwrap_err();
+	RelativeMut::<u8, Add, _16>::from(s!(25)).relative_to(vec![0u8; 8]).unwrap_err();
+}
@@ -1,4 +1,7 @@
-use len_constraints::type_math::*;
+#![allow(clippy::from_str_radix_10, clippy::legacy_numeric_constants)]
+
+#[macro_use] extern crate len_constraints;
+use len_constraints::{ ConstraintViolation, type_math::* };
 
 
 macro_rules! test_type_num {
@@ -24,6 +27,27 @@ fn test_type_num() {
 		_32768, _65536
 	}
 }
+#[test]
+fn test_const() {
+	assert_eq!(Const::<0>::VALUE, 0);
+	assert_eq!(Const::<1500>::VALUE, 1500);
+	assert_eq!(Const::<100_000>::value(), 100_000);
+}
+#[test]
+fn test_arithmetic_type_num() {
+	assert_eq!(Sum::<_32, _16>::VALUE, 48);
+	assert_eq!(Diff::<_32, _12>::VALUE, 20);
+	assert_eq!(Product::<_16, _4>::VALUE, 64);
+	assert_eq!(Sum::<Product<_16, _4>, Const<1>>::VALUE, 65);
+}
+#[test]
+fn test_tn() {
+	assert_eq!(<tn!(1500)>::VALUE, 1500);
+	assert_eq!(<tn!(2 * 750)>::VALUE, 1500);
+	
+	let value: tn!(100_000) = Const::<100_000>;
+	assert_eq!(format!("{:?}", value), "Const");
+}
 
 
 #[test]
@@ -32,7 +56,7 @@ fn test_add() {
 }
 #[test]
 fn test_add_err() {
-	Add::r#do(usize::max_value(), 1).unwrap_err();
+	assert_eq!(Add::r#do(usize::MAX, 1).unwrap_err(), ConstraintViolation::Overflow);
 }
 
 
@@ -42,7 +66,7 @@ fn test_sub() {
 }
 #[test]
 fn test_sub_err() {
-	Sub::r#do(0, 1).unwrap_err();
+	assert_eq!(Sub::r#do(0, 1).unwrap_err(), ConstraintViolation::Underflow);
 }
 
 
@@ -52,7 +76,7 @@ fn test_mul() {
 }
 #[test]
 fn test_mul_err() {
-	Mul::r#do(usize::max_value(), 2).unwrap_err();
+	assert_eq!(Mul::r#do(usize::MAX, 2).unwrap_err(), ConstraintViolation::Overflow);
 }
 
 
@@ -62,5 +86,169 @@ fn test_div() {
 }
 #[test]
 fn test_div_err() {
-	Div::r#do(21, 0).unwrap_err();
-}
\ No newline at end of file
+	assert_eq!(Div::r#do(21, 0).unwrap_err(), ConstraintViolation::DivisionByZero);
+}
+
+
+/// Wraps an operator but uses the default (binary search) inverse functions
+#[derive(Debug, Default)]
+struct Searched<Op: Operator>(std::marker::PhantomData<Op>);
+impl<Op: Operator> Operator for Searched<Op> {
+	fn r#do(a: usize, b: usize) -> Result<usize, ConstraintViolation> {
+		Op::r#do(a, b)
+	}
+}
+macro_rules! test_inverse {
+	($op:ident: $($result:expr, $b:expr => $floor:expr, $ceil:expr;)+) => ({$(
+		assert_eq!($op::inverse_floor($result, $b), $floor);
+		assert_eq!($op::inverse_ceil($result, $b), $ceil);
+		assert_eq!(Searched::<$op>::inverse_floor($result, $b), $floor);
+		assert_eq!(Searched::<$op>::inverse_ceil($result, $b), $ceil);
+	)+});
+}
+#[test]
+fn test_inverse() {
+	test_inverse!(Add:
+		25, 16 => Some(9), Some(9);
+		15, 16 => None, Some(0);
+		usize::MAX, 1 => Some(usize::MAX - 1), Some(usize::MAX - 1);
+	);
+	test_inverse!(Sub:
+		4, 4 => Some(8), Some(8);
+		0, 4 => Some(4), Some(4);
+		usize::MAX, 1 => Some(usize::MAX), None;
+	);
+	test_inverse!(Mul:
+		21, 4 => Some(5), Some(6);
+		20, 4 => Some(5), Some(5);
+		7, 0 => Some(usize::MAX), None;
+		usize::MAX, 2 => Some(usize::MAX / 2), None;
+	);
+	test_inverse!(Div:
+		3, 4 => Some(15), Some(12);
+		0, 4 => Some(3), Some(0);
+		usize::MAX, 2 => Some(usize::MAX), None;
+		3, 0 => None, None;
+	);
+}
+
+
+#[test]
+fn test_ceil_div() {
+	assert_eq!(CeilDiv::r#do(1025, 512).unwrap(), 3);
+	assert_eq!(CeilDiv::r#do(1024, 512).unwrap(), 2);
+	assert_eq!(CeilDiv::r#do(0, 512).unwrap(), 0);
+}
+#[test]
+fn test_ceil_div_err() {
+	assert_eq!(CeilDiv::r#do(7, 0).unwrap_err(), ConstraintViolation::DivisionByZero);
+}
+
+
+#[test]
+fn test_align_up() {
+	assert_eq!(AlignUp::r#do(17, 16).unwrap(), 32);
+	assert_eq!(AlignUp::r#do(32, 16).unwrap(), 32);
+	assert_eq!(AlignUp::r#do(0, 16).unwrap(), 0);
+}
+#[test]
+fn test_align_up_err() {
+	assert_eq!(AlignUp::r#do(7, 0).unwrap_err(), ConstraintViolation::DivisionByZero);
+	assert_eq!(AlignUp::r#do(usize::MAX, 16).unwrap_err(), ConstraintViolation::Overflow);
+}
+
+
+#[test]
+fn test_mod() {
+	assert_eq!(Mod::r#do(17, 16).unwrap(), 1);
+	assert_eq!(Mod::r#do(32, 16).unwrap(), 0);
+}
+#[test]
+fn test_mod_err() {
+	assert_eq!(Mod::r#do(7, 0).unwrap_err(), ConstraintViolation::DivisionByZero);
+}
+
+
+#[test]
+fn test_shl() {
+	assert_eq!(Shl::r#do(3, 4).unwrap(), 48);
+	assert_eq!(Shl::r#do(1, 0).unwrap(), 1);
+}
+#[test]
+fn test_shl_err() {
+	assert_eq!(Shl::r#do(usize::MAX, 1).unwrap_err(), ConstraintViolation::Overflow);
+	assert_eq!(Shl::r#do(0, 64).unwrap_err(), ConstraintViolation::Overflow);
+	assert_eq!(Shl::r#do(1, usize::BITS as usize).unwrap_err(), ConstraintViolation::Overflow);
+	assert_eq!(Shl::r#do(1, usize::MAX).unwrap_err(), ConstraintViolation::Overflow);
+}
+
+
+#[test]
+fn test_shr() {
+	assert_eq!(Shr::r#do(48, 4).unwrap(), 3);
+	assert_eq!(Shr::r#do(49, 4).unwrap(), 3);
+}
+#[test]
+fn test_shr_out_of_range() {
+	assert_eq!(Shr::r#do(1, usize::BITS as usize), Ok(0));
+	assert_eq!(Shr::r#do(usize::MAX, usize::BITS as usize), Ok(0));
+	assert_eq!(Shr::r#do(1, usize::MAX), Ok(0));
+}
+
+
+#[test]
+fn test_block_inverse() {
+	test_inverse!(CeilDiv:
+		3, 512 => Some(1536), Some(1025);
+		0, 512 => Some(0), Some(0);
+		3, 0 => None, None;
+	);
+	test_inverse!(AlignUp:
+		32, 16 => Some(32), Some(17);
+		31, 16 => Some(16), Some(17);
+		3, 0 => None, None;
+	);
+	test_inverse!(Shl:
+		48, 4 => Some(3), Some(3);
+		49, 4 => Some(3), Some(4);
+	);
+	test_inverse!(Shr:
+		3, 4 => Some(63), Some(48);
+	);
+	
+	assert_eq!(Mod::inverse_floor(3, 16), Some(usize::MAX - 12));
+	assert_eq!(Mod::inverse_ceil(3, 16), Some(3));
+	assert_eq!(Mod::inverse_ceil(16, 16), None);
+}
+
+
+/// The base64 length `ceil(n / 3) * 4`
+type Base64 = Then<CeilDiv, tn!(3), Mul>;
+/// The PKCS#7 padded length `(n / 16 + 1) * 16`
+type Pkcs7 = Then<Then<Div, _16, Add>, _1, Mul>;
+#[test]
+fn test_then() {
+	assert_eq!(Base64::r#do(0, 4).unwrap(), 0);
+	assert_eq!(Base64::r#do(5, 4).unwrap(), 8);
+	assert_eq!(Base64::r#do(6, 4).unwrap(), 8);
+	assert_eq!(Pkcs7::r#do(15, 16).unwrap(), 16);
+	assert_eq!(Pkcs7::r#do(16, 16).unwrap(), 32);
+	
+	assert_eq!(format!("{:?}", Base64::default()), "(ceil(x / 3)) * b");
+	assert_eq!(format!("{:?}", Pkcs7::default()), "((x / 16) + 1) * b");
+}
+#[test]
+fn test_then_err() {
+	assert_eq!(Pkcs7::r#do(usize::MAX, 16).unwrap_err(), ConstraintViolation::Overflow);
+	assert_eq!(Then::<Add, _0, Div>::r#do(7, 0).unwrap_err(), ConstraintViolation::DivisionByZero);
+}
+#[test]
+fn test_then_inverse() {
+	test_inverse!(Base64:
+		8, 4 => Some(6), Some(4);
+		10, 4 => Some(6), Some(7);
+	);
+	test_inverse!(Pkcs7:
+		32, 16 => Some(31), Some(16);
+	);
+}